@@ -0,0 +1,356 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    fmt::Display,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use crate::runner::{self, Part, Solver};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<runner::Error> for Error {
+    fn from(value: runner::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// the counters are process wide, so only one thing should be profiled at a time
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+// wraps the system allocator and counts everything that goes through it, installed by the alloc-profile feature
+pub struct Counting;
+
+fn allocated(size: u64) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let result = System.alloc(layout);
+        if !result.is_null() {
+            allocated(layout.size() as u64);
+        }
+        result
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let result = System.alloc_zeroed(layout);
+        if !result.is_null() {
+            allocated(layout.size() as u64);
+        }
+        result
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    // counted as freeing the old block and allocating the new one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let result = System.realloc(ptr, layout, new_size);
+        if !result.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            allocated(new_size as u64);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "alloc-profile")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+pub fn is_enabled() -> bool {
+    cfg!(feature = "alloc-profile")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // the most bytes that were live at once, above what was live when measuring started
+    pub peak: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    allocated: u64,
+    live: u64,
+    peak: u64,
+}
+
+fn counters() -> Counters {
+    Counters {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+        live: LIVE.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed),
+    }
+}
+
+fn reset_peak() {
+    PEAK.store(LIVE.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+fn between(start: &Counters, end: &Counters, baseline_live: u64) -> Stats {
+    Stats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.allocated - start.allocated,
+        peak: end.peak.saturating_sub(baseline_live),
+    }
+}
+
+// set while a solver is being profiled, so parsed() is free the rest of the time
+static PROFILING: AtomicBool = AtomicBool::new(false);
+static PARSED: Mutex<Option<Counters>> = Mutex::new(None);
+
+// solvers call this once their input is parsed, so parsing can be reported separately from solving
+pub fn parsed() {
+    if PROFILING.load(Ordering::Relaxed) {
+        *PARSED.lock().unwrap() = Some(counters());
+        reset_peak();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measured {
+    // only known for solvers that call parsed()
+    pub parse: Option<Stats>,
+    // everything after parsing, or everything if parsing isn't marked
+    pub solve: Stats,
+}
+
+pub fn measure(solver: &Solver, path: &str) -> Result<Measured> {
+    if !is_enabled() {
+        Err("allocation profiling needs the alloc-profile feature, e.g. cargo run --release --features alloc-profile")?;
+    }
+    *PARSED.lock().unwrap() = None;
+    PROFILING.store(true, Ordering::SeqCst);
+    reset_peak();
+    let start = counters();
+    // a panicking solver (the unfinished ones) shouldn't take the rest of a run down with it
+    let result = std::panic::catch_unwind(|| solver.solve(path, &[]));
+    let end = counters();
+    PROFILING.store(false, Ordering::SeqCst);
+    result.map_err(|_| format!("{} panicked", solver.name()))??;
+
+    Ok(match PARSED.lock().unwrap().take() {
+        Some(parsed) => Measured {
+            parse: Some(between(&start, &parsed, start.live)),
+            solve: between(&parsed, &end, start.live),
+        },
+        None => Measured {
+            parse: None,
+            solve: between(&start, &end, start.live),
+        },
+    })
+}
+
+// one row per day and phase, where phase is parse, part1, or part2
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub rows: BTreeMap<(u32, String), Stats>,
+}
+
+impl Profile {
+    pub fn add(&mut self, solver: &Solver, measured: &Measured) {
+        // both parts usually parse the same way, so part 1's parse stands for the day
+        if let (Part::A, Some(parse)) = (solver.part, measured.parse) {
+            self.rows.insert((solver.day, "parse".to_string()), parse);
+        }
+        let phase = match solver.part {
+            Part::A => "part1",
+            Part::B => "part2",
+        };
+        self.rows.insert((solver.day, phase.to_string()), measured.solve);
+    }
+
+    // lines of "day\tphase\tallocations\tbytes\tpeak"
+    pub fn to_tsv(&self) -> String {
+        self.rows
+            .iter()
+            .map(|((day, phase), s)| format!("{day}\t{phase}\t{}\t{}\t{}\n", s.allocations, s.bytes, s.peak))
+            .collect()
+    }
+
+    pub fn from_tsv(s: &str) -> Result<Self> {
+        let mut rows = BTreeMap::new();
+        for (i, line) in s.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let number = |index: usize| -> Result<u64> {
+                Ok(fields[index]
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid number: {}", i + 1, fields[index]))?)
+            };
+            if fields.len() != 5 {
+                Err(format!("line {}: expected 5 tab separated fields", i + 1))?;
+            }
+            rows.insert(
+                (number(0)? as u32, fields[1].to_string()),
+                Stats {
+                    allocations: number(2)?,
+                    bytes: number(3)?,
+                    peak: number(4)?,
+                },
+            );
+        }
+        Ok(Self { rows })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_tsv(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn compare<'a>(&'a self, base: &'a Profile) -> Comparison<'a> {
+        Comparison { base, new: self }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:>5} {:>6} {:>12} {:>14} {:>14}", "day", "phase", "allocations", "bytes", "peak bytes")?;
+        for ((day, phase), s) in self.rows.iter() {
+            writeln!(f, "{day:>5} {phase:>6} {:>12} {:>14} {:>14}", s.allocations, s.bytes, s.peak)?;
+        }
+        Ok(())
+    }
+}
+
+// a profile next to an earlier one, with the change in each number
+pub struct Comparison<'a> {
+    base: &'a Profile,
+    new: &'a Profile,
+}
+
+fn change(base: u64, new: u64) -> String {
+    match base {
+        0 if new == 0 => "same".to_string(),
+        0 => "new".to_string(),
+        _ => format!("{:+.1}%", (new as f64 - base as f64) * 100.0 / base as f64),
+    }
+}
+
+impl Display for Comparison<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>5} {:>6} {:>12} {:>9} {:>14} {:>9} {:>14} {:>9}",
+            "day", "phase", "allocations", "change", "bytes", "change", "peak bytes", "change"
+        )?;
+        let keys = self.base.rows.keys().chain(self.new.rows.keys()).collect::<std::collections::BTreeSet<_>>();
+        for key in keys {
+            let (day, phase) = key;
+            match (self.base.rows.get(key), self.new.rows.get(key)) {
+                (Some(b), Some(n)) => writeln!(
+                    f,
+                    "{day:>5} {phase:>6} {:>12} {:>9} {:>14} {:>9} {:>14} {:>9}",
+                    n.allocations,
+                    change(b.allocations, n.allocations),
+                    n.bytes,
+                    change(b.bytes, n.bytes),
+                    n.peak,
+                    change(b.peak, n.peak)
+                )?,
+                (None, Some(n)) => writeln!(
+                    f,
+                    "{day:>5} {phase:>6} {:>12} {:>9} {:>14} {:>9} {:>14} {:>9}",
+                    n.allocations, "new", n.bytes, "new", n.peak, "new"
+                )?,
+                (Some(_), None) => writeln!(f, "{day:>5} {phase:>6} {:>12}", "missing")?,
+                (None, None) => unreachable!(),
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(allocations: u64, bytes: u64, peak: u64) -> Stats {
+        Stats { allocations, bytes, peak }
+    }
+
+    #[test]
+    pub fn test_tsv() {
+        let mut profile = Profile::default();
+        profile.rows.insert((22, "parse".to_string()), stats(10, 1000, 800));
+        profile.rows.insert((22, "part2".to_string()), stats(5000000, 900000000, 40000000));
+        let tsv = profile.to_tsv();
+        assert_eq!(tsv, "22\tparse\t10\t1000\t800\n22\tpart2\t5000000\t900000000\t40000000\n");
+        assert_eq!(Profile::from_tsv(&tsv).unwrap(), profile);
+        assert!(Profile::from_tsv("22\tparse\t10\n").is_err());
+        assert!(Profile::from_tsv("22\tparse\tx\t1\t1\n").is_err());
+    }
+
+    #[test]
+    pub fn test_compare() {
+        let mut base = Profile::default();
+        base.rows.insert((6, "part2".to_string()), stats(200, 4000, 100));
+        base.rows.insert((15, "part2".to_string()), stats(10, 10, 10));
+        let mut new = Profile::default();
+        new.rows.insert((6, "part2".to_string()), stats(100, 4000, 150));
+        new.rows.insert((22, "part2".to_string()), stats(1, 1, 1));
+        let lines = new.compare(&base).to_string().lines().map(|line| line.split_whitespace().collect::<Vec<_>>().join(" ")).collect::<Vec<_>>();
+        assert_eq!(
+            lines[1..],
+            [
+                "6 part2 100 -50.0% 4000 +0.0% 150 +50.0%",
+                "15 part2 missing",
+                "22 part2 1 new 1 new 1 new"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_measure() {
+        let solver = runner::find_by_name("day22b").unwrap();
+        let result = measure(solver, "day22b-sample.txt");
+        if !is_enabled() {
+            assert!(result.is_err());
+            return;
+        }
+        let measured = result.unwrap();
+        let parse = measured.parse.unwrap();
+        assert!(parse.allocations > 0);
+        // the sequences are packed into keys of two tables sized up front, so solving allocates the same few
+        // times however many steps there are, and all of it is still live at the end
+        assert!(measured.solve.allocations <= 4);
+        assert!(measured.solve.peak >= measured.solve.bytes);
+    }
+}
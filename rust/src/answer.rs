@@ -0,0 +1,217 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+// a puzzle answer, whatever type the day's solver happened to return
+// values are kept normalised, so equal answers compare equal however they were produced
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    // sign and magnitude, so everything from i8 to u128 fits; zero is never negative
+    Integer { negative: bool, magnitude: u128 },
+    // an "x,y" pair, like day 18's first blocking byte
+    Coordinate(i128, i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn integer(negative: bool, magnitude: u128) -> Self {
+        Answer::Integer {
+            negative: negative && magnitude != 0,
+            magnitude,
+        }
+    }
+
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Integer { negative: false, magnitude } => i128::try_from(*magnitude).ok(),
+            Answer::Integer { negative: true, magnitude } => 0i128.checked_sub_unsigned(*magnitude),
+            _ => None,
+        }
+    }
+
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Answer::Integer { negative: false, magnitude } => Some(*magnitude),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer { negative, magnitude } => write!(f, "{}{magnitude}", if *negative { "-" } else { "" }),
+            Answer::Coordinate(x, y) => write!(f, "{x},{y}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+fn parse_integer(s: &str) -> Option<Answer> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(Answer::integer(negative, digits.parse().ok()?))
+}
+
+// integers and coordinates are recognised, anything else is kept as text
+impl FromStr for Answer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            Err("empty answer")?;
+        }
+        if let Some(integer) = parse_integer(s) {
+            return Ok(integer);
+        }
+        if let Some((x, y)) = s.split_once(',') {
+            if let (Some(x), Some(y)) = (parse_integer(x.trim()), parse_integer(y.trim())) {
+                if let (Some(x), Some(y)) = (x.as_i128(), y.as_i128()) {
+                    return Ok(Answer::Coordinate(x, y));
+                }
+            }
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::integer(false, value as u128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_u128() == Some(*other as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::integer(value < 0, (value as i128).unsigned_abs())
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_i128() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+from_signed!(i8, i16, i32, i64, i128, isize);
+
+// strings are parsed, so "007" from one solver equals 7 from another, and an empty one is an error
+impl TryFrom<&str> for Answer {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Answer {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl<T: Into<i128>> From<(T, T)> for Answer {
+    fn from((x, y): (T, T)) -> Self {
+        Answer::Coordinate(x.into(), y.into())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Answer>().is_ok_and(|other| other == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_and_display() {
+        for (s, expected, canonical) in [
+            ("1319616", Answer::from(1319616u32), "1319616"),
+            ("  007\n", Answer::from(7u8), "7"),
+            ("+12", Answer::from(12i64), "12"),
+            ("-0", Answer::from(0u64), "0"),
+            ("-875318608908", Answer::from(-875318608908i64), "-875318608908"),
+            ("340282366920938463463374607431768211455", Answer::from(u128::MAX), "340282366920938463463374607431768211455"),
+            ("6,1", Answer::from((6, 1)), "6,1"),
+            (" 20, -3 ", Answer::Coordinate(20, -3), "20,-3"),
+            ("4,6,3,5,6,3,5,2,1,0", Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string()), "4,6,3,5,6,3,5,2,1,0"),
+            ("co,de,ka,ta", Answer::Text("co,de,ka,ta".to_string()), "co,de,ka,ta"),
+            ("-", Answer::Text("-".to_string()), "-"),
+        ] {
+            let parsed = s.parse::<Answer>().unwrap();
+            assert_eq!(parsed, expected, "{s:?}");
+            assert_eq!(parsed.to_string(), canonical);
+            assert_eq!(canonical.parse::<Answer>().unwrap(), parsed);
+        }
+        assert!("".parse::<Answer>().is_err());
+        assert!(" ".parse::<Answer>().is_err());
+    }
+
+    #[test]
+    pub fn test_numeric_equality() {
+        assert_eq!(Answer::from(41usize), Answer::from(41u32));
+        assert_eq!(Answer::from(41i64), Answer::try_from("41").unwrap());
+        assert_eq!(Answer::from(41u128), 41u8);
+        assert_eq!(Answer::try_from("0041".to_string()).unwrap(), 41);
+        assert_ne!(Answer::from(-41i32), Answer::from(41u32));
+        assert_ne!(Answer::try_from("41a").unwrap(), 41);
+        assert!(Answer::try_from(" \n").is_err());
+        assert!(Answer::try_from(String::new()).is_err());
+        assert_eq!(Answer::from(-5i8), "-5");
+    }
+
+    #[test]
+    pub fn test_accessors() {
+        assert_eq!(Answer::from(-5i64).as_i128(), Some(-5));
+        assert_eq!(Answer::from(-5i64).as_u128(), None);
+        assert_eq!(Answer::from(i128::MIN).as_i128(), Some(i128::MIN));
+        assert_eq!(Answer::from(u128::MAX).as_i128(), None);
+        assert_eq!(Answer::from(u128::MAX).as_u128(), Some(u128::MAX));
+        assert_eq!(Answer::from((1, 2)).as_i128(), None);
+    }
+}
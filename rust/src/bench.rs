@@ -0,0 +1,197 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    generate,
+    runner::{self, Outcome, Solver},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<generate::Error> for Error {
+    fn from(value: generate::Error) -> Self {
+        Self(format!("generate::Error({:?})", value.0))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub scale: usize,
+    pub bytes: usize,
+    pub elapsed: Duration,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub solver: String,
+    pub unit: &'static str,
+    pub samples: Vec<Sample>,
+    // the first scale that didn't finish within the time budget, if any
+    pub timed_out_at: Option<usize>,
+}
+
+impl Report {
+    // slope of log(time) against log(input bytes), so 1.0 is linear, 2.0 is quadratic, etc
+    pub fn exponent(&self) -> Option<f64> {
+        fit_exponent(
+            &self
+                .samples
+                .iter()
+                .map(|s| (s.bytes as f64, s.elapsed.as_secs_f64()))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} (scale = {})", self.solver, self.unit)?;
+        writeln!(f, "{:>12} {:>14} {:>14}  answer", "scale", "input bytes", "time")?;
+        for s in self.samples.iter() {
+            writeln!(
+                f,
+                "{:>12} {:>14} {:>14}  {}",
+                s.scale,
+                s.bytes,
+                format!("{:.3?}", s.elapsed),
+                s.answer
+            )?;
+        }
+        if let Some(scale) = self.timed_out_at {
+            writeln!(f, "{:>12} {:>14} {:>14}", scale, "", "timed out")?;
+        }
+        match self.exponent() {
+            Some(exponent) => writeln!(f, "empirical complexity: O(n^{exponent:.2}) in input bytes"),
+            None => writeln!(f, "empirical complexity: not enough samples"),
+        }
+    }
+}
+
+fn fit_exponent(points: &[(f64, f64)]) -> Option<f64> {
+    // timings too close to zero are mostly noise, leave them out of the fit
+    let points = points
+        .iter()
+        .filter(|(x, y)| *x > 0.0 && *y > 1e-5)
+        .map(|(x, y)| (x.ln(), y.ln()))
+        .collect::<Vec<_>>();
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>();
+    let variance = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>();
+    if variance == 0.0 {
+        None
+    } else {
+        Some(covariance / variance)
+    }
+}
+
+// run the solver on a generated input at each scale in turn, stopping at the first one that takes longer than budget
+pub fn scaling(solver: &'static Solver, scales: &[usize], seed: u64, budget: Duration) -> Result<Report> {
+    if solver.year != 2024 {
+        Err(format!("generators only exist for 2024, not {}", solver.year))?;
+    }
+    let generator = generate::find(solver.day).ok_or(format!("no generator for day {}", solver.day))?;
+    let mut report = Report {
+        solver: solver.name(),
+        unit: generator.unit,
+        samples: Vec::new(),
+        timed_out_at: None,
+    };
+
+    for scale in scales.iter().copied() {
+        let generated = generator.generate(scale, seed)?;
+        let path = std::env::temp_dir().join(format!(
+            "aoc-bench-{}-{}-{scale}-{seed}.txt",
+            std::process::id(),
+            solver.name()
+        ));
+        std::fs::write(&path, &generated.input)?;
+
+        let outcome = runner::solve_with_timeout(solver, &path.to_string_lossy(), &generated.params_for(solver), budget);
+        std::fs::remove_file(&path)?;
+        match outcome {
+            Outcome::Solved { answer, elapsed } => report.samples.push(Sample {
+                scale,
+                bytes: generated.input.len(),
+                elapsed,
+                answer: answer.to_string(),
+            }),
+            Outcome::Failed { error, .. } => Err(format!("{} failed at scale {scale}: {error}", solver.name()))?,
+            Outcome::Panicked => Err(format!("{} panicked at scale {scale}", solver.name()))?,
+            Outcome::TimedOut => {
+                report.timed_out_at = Some(scale);
+                break;
+            }
+        }
+    }
+    Ok(report)
+}
+
+// powers of two from start up to and including end, clamped to what the day's generator accepts
+pub fn default_scales(day: u32, start: usize, end: usize) -> Vec<usize> {
+    let (min, max) = generate::find(day)
+        .map(|g| (g.min_scale, g.max_scale))
+        .unwrap_or((1, usize::MAX));
+    let mut result = Vec::new();
+    let mut scale = start.max(min);
+    while scale <= end.min(max) {
+        result.push(scale);
+        scale *= 2;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::find_by_name;
+
+    #[test]
+    pub fn test_fit_exponent() {
+        let linear = (1..10).map(|x| (x as f64, 3.0 * x as f64)).collect::<Vec<_>>();
+        assert!((fit_exponent(&linear).unwrap() - 1.0).abs() < 1e-9);
+        let quadratic = (1..10).map(|x| (x as f64, (x * x) as f64)).collect::<Vec<_>>();
+        assert!((fit_exponent(&quadratic).unwrap() - 2.0).abs() < 1e-9);
+        assert!(fit_exponent(&[(1.0, 1.0)]).is_none());
+    }
+
+    #[test]
+    pub fn test_default_scales() {
+        assert_eq!(default_scales(9, 1000, 10000), vec![1000, 2000, 4000, 8000]);
+        assert_eq!(default_scales(17, 4, 64), vec![4, 8, 16]);
+    }
+
+    #[test]
+    pub fn test_scaling() {
+        let solver = find_by_name("day01a").unwrap();
+        let report = scaling(solver, &[10, 20, 40], 1, Duration::from_secs(60)).unwrap();
+        assert_eq!(report.samples.len(), 3);
+        assert!(report.timed_out_at.is_none());
+        assert!(report.samples[0].bytes < report.samples[2].bytes);
+    }
+}
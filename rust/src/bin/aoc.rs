@@ -0,0 +1,465 @@
+use std::{
+    net::TcpListener,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2024::{alloc_profile, answer::{self, Answer}, bench, cross_check, fetch, generate, leaderboard, progress, runner, samples, scaffold, server, submit, test_vectors};
+
+const USAGE: &str = "usage:
+  aoc run <dayNNx> [--input PATH] [--progress auto|terminal|log|off] [--<param> N]...
+  aoc generate <day> <scale> [--seed N] [--out PATH]
+  aoc bench <dayNNx> [--from N] [--to N] [--seed N] [--budget SECONDS]
+  aoc cross-check <dayNNx>... | all [--seeds N] [--max-scale N] [--timeout SECONDS]
+  aoc serve [--addr HOST:PORT] [--timeout SECONDS] [--max-running N]
+  aoc fetch <day>... | all [--year N]   (needs AOC_SESSION, optionally AOC_BASE_URL)
+  aoc submit <[year/]dayNNx> [answer] [--history PATH]   (solves the real input if no answer given)
+  aoc samples <day> <saved puzzle page.html> [--year N] [--out DIR]
+  aoc new <day> [--year N]
+  aoc analyse <day> [--year N] [--input PATH] [--<param> N]...
+  aoc leaderboard <private leaderboard export.json>
+  aoc vectors export [--out PATH] [--timeout SECONDS]
+  aoc vectors diff <results.tsv> [--vectors PATH]
+  aoc profile <day | dayNNx>... | all [--skip day17b,...] [--save PATH] [--compare PATH]
+      (needs --features alloc-profile)
+
+solvers are named like day05b, or 2024/day05b for a year other than the default";
+
+#[derive(Debug)]
+struct Error(String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<runner::Error> for Error {
+    fn from(value: runner::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<generate::Error> for Error {
+    fn from(value: generate::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<answer::Error> for Error {
+    fn from(value: answer::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<alloc_profile::Error> for Error {
+    fn from(value: alloc_profile::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<bench::Error> for Error {
+    fn from(value: bench::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<cross_check::Error> for Error {
+    fn from(value: cross_check::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<leaderboard::Error> for Error {
+    fn from(value: leaderboard::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<test_vectors::Error> for Error {
+    fn from(value: test_vectors::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<fetch::Error> for Error {
+    fn from(value: fetch::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<submit::Error> for Error {
+    fn from(value: submit::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<samples::Error> for Error {
+    fn from(value: samples::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<scaffold::Error> for Error {
+    fn from(value: scaffold::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<server::Error> for Error {
+    fn from(value: server::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// positional arguments in order, plus --name value options
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl Args {
+    fn new(args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = args.next().ok_or(format!("missing value for --{name}"))?;
+                options.push((name.to_string(), value));
+            } else {
+                positional.push(arg);
+            }
+        }
+        Ok(Self { positional, options })
+    }
+
+    fn positional(&self, index: usize, name: &str) -> Result<&str> {
+        Ok(self
+            .positional
+            .get(index)
+            .ok_or(format!("missing argument: {name}\n{USAGE}"))?)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.iter().rev().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    fn number_option<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.option(name) {
+            Some(value) => Ok(value.parse().map_err(|_| format!("invalid --{name}: {value}"))?),
+            None => Ok(default),
+        }
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T> {
+    Ok(value.parse().map_err(|_| format!("invalid {name}: {value}"))?)
+}
+
+fn generate_command(args: &Args) -> Result<()> {
+    let day = parse_number::<u32>("day", args.positional(1, "day")?.trim_start_matches("day"))?;
+    let scale = parse_number::<usize>("scale", args.positional(2, "scale")?)?;
+    let seed = args.number_option("seed", 0u64)?;
+    let generated = generate::find(day)
+        .ok_or(format!("no generator for day {day}"))?
+        .generate(scale, seed)?;
+    match args.option("out") {
+        Some(path) => std::fs::write(path, &generated.input)?,
+        None => print!("{}", generated.input),
+    };
+    for (name, value) in generated.params.iter() {
+        eprintln!("{name} = {value}");
+    }
+    Ok(())
+}
+
+fn bench_command(args: &Args) -> Result<()> {
+    let solver = runner::find_by_name(args.positional(1, "solver")?)?;
+    let from = args.number_option("from", 16usize)?;
+    let to = args.number_option("to", 1 << 20)?;
+    let seed = args.number_option("seed", 0u64)?;
+    let budget = Duration::from_secs_f64(args.number_option("budget", 10.0)?);
+    let scales = bench::default_scales(solver.day, from, to);
+    print!("{}", bench::scaling(solver, &scales, seed, budget)?);
+    Ok(())
+}
+
+fn cross_check_command(args: &Args) -> Result<()> {
+    let defaults = cross_check::Config::default();
+    let config = cross_check::Config {
+        seeds: args.number_option("seeds", defaults.seeds)?,
+        max_scale: args.number_option("max-scale", defaults.max_scale)?,
+        timeout: Duration::from_secs_f64(args.number_option("timeout", defaults.timeout.as_secs_f64())?),
+    };
+    let solvers = match args.positional(1, "solver")? {
+        "all" => runner::SOLVERS.iter().filter(|s| s.strategies().len() > 1).collect::<Vec<_>>(),
+        _ => args.positional[1..]
+            .iter()
+            .map(|name| runner::find_by_name(name))
+            .collect::<std::result::Result<Vec<_>, _>>()?,
+    };
+
+    // shrinking runs the strategies on all sorts of broken inputs, and a panic message for each is just noise
+    std::panic::set_hook(Box::new(|_| {}));
+    let mut disagreements = 0;
+    for solver in solvers {
+        let names = solver.strategies().iter().map(|s| s.name).collect::<Vec<_>>().join(", ");
+        eprintln!("{}: checking {names}...", solver.name());
+        match cross_check::cross_check(solver, &config)? {
+            Some(disagreement) => {
+                print!("{disagreement}");
+                disagreements += 1;
+            }
+            None => println!("{} strategies agree", solver.name()),
+        };
+    }
+    if disagreements > 0 {
+        Err(format!("{disagreements} disagreement(s)"))?;
+    }
+    Ok(())
+}
+
+fn analyse_command(args: &Args) -> Result<()> {
+    let day = parse_number::<u32>("day", args.positional(1, "day")?.trim_start_matches("day"))?;
+    let year = args.number_option("year", runner::DEFAULT_YEAR)?;
+    let analysis = runner::find_analysis(year, day).ok_or(format!("no analysis for {year} day {day}"))?;
+    let input = args.option("input").map(|s| s.to_string()).unwrap_or(analysis.input());
+    let overrides = args
+        .options
+        .iter()
+        .filter(|(name, _)| name != "input" && name != "year")
+        .map(|(name, value)| Ok((name.clone(), parse_number::<u64>(name, value)?)))
+        .collect::<Result<Vec<_>>>()?;
+    print!("{}", analysis.analyse(&input, &overrides)?);
+    Ok(())
+}
+
+fn leaderboard_command(args: &Args) -> Result<()> {
+    let path = args.positional(1, "leaderboard export")?;
+    print!("{}", leaderboard::Leaderboard::load(std::path::Path::new(path))?.report());
+    Ok(())
+}
+
+fn vectors_command(args: &Args) -> Result<()> {
+    let path = |name| args.option(name).map(std::path::PathBuf::from).unwrap_or_else(test_vectors::default_path);
+    match args.positional(1, "export or diff")? {
+        "export" => {
+            let timeout = Duration::from_secs_f64(args.number_option("timeout", 60.0)?);
+            let export = test_vectors::export(timeout)?;
+            for (vector, problem) in export.skipped.iter() {
+                eprintln!("skipped {}/day{:02}{} {}: {problem}", vector.year, vector.day, vector.part, vector.input);
+            }
+            let out = path("out");
+            test_vectors::write(&out, &export.vectors)?;
+            println!("wrote {} vectors to {}", export.vectors.len(), out.display());
+        }
+        "diff" => {
+            let results = test_vectors::read(std::path::Path::new(args.positional(2, "results")?))?;
+            let diff = test_vectors::diff(&test_vectors::read(&path("vectors"))?, &results);
+            print!("{diff}");
+            if !diff.is_clean() {
+                Err("results don't match the test vectors")?;
+            }
+        }
+        other => Err(format!("expected export or diff, got {other}"))?,
+    };
+    Ok(())
+}
+
+fn serve_command(args: &Args) -> Result<()> {
+    let addr = args.option("addr").unwrap_or("127.0.0.1:8024");
+    let defaults = server::Config::default();
+    let config = server::Config {
+        timeout: Duration::from_secs_f64(args.number_option("timeout", defaults.timeout.as_secs_f64())?),
+        max_running: args.number_option("max-running", defaults.max_running)?,
+    };
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("listening on http://{}", listener.local_addr()?);
+    server::serve(listener, config)?;
+    Ok(())
+}
+
+fn fetch_command(args: &Args) -> Result<()> {
+    let days = match args.positional(1, "day")? {
+        "all" => (1..=25).collect::<Vec<_>>(),
+        _ => args.positional[1..]
+            .iter()
+            .map(|day| parse_number::<u32>("day", day.trim_start_matches("day")))
+            .collect::<Result<Vec<_>>>()?,
+    };
+    let year = args.number_option("year", runner::DEFAULT_YEAR)?;
+    let mut client = fetch::Client::new(fetch::Config::from_env(year)?);
+    for day in days {
+        match client.fetch_input(day)? {
+            fetch::Fetched::Cached(path) => println!("day {day}: already have {}", path.display()),
+            fetch::Fetched::Downloaded(path) => println!("day {day}: saved {}", path.display()),
+        };
+    }
+    Ok(())
+}
+
+fn submit_command(args: &Args) -> Result<()> {
+    let solver = runner::find_by_name(args.positional(1, "solver")?)?;
+    let answer = match args.positional.get(2) {
+        // typed answers go out in the same canonical form a solver's would, e.g. 0042 as 42
+        Some(answer) => answer.parse::<Answer>()?,
+        None => {
+            let answer = solver.solve(&solver.input(), &[])?;
+            println!("{} = {answer}", solver.name());
+            answer
+        }
+    };
+    let history_path = match args.option("history") {
+        Some(path) => path.into(),
+        None => submit::History::default_path(solver.year),
+    };
+    let mut history = submit::History::load(&history_path)?;
+    let mut client = fetch::Client::new(fetch::Config::from_env(solver.year)?);
+    let outcome = submit::submit(&mut client, &mut history, solver.day, solver.part, &answer.to_string())?;
+    println!("{answer}: {outcome}");
+    Ok(())
+}
+
+fn samples_command(args: &Args) -> Result<()> {
+    let day = parse_number::<u32>("day", args.positional(1, "day")?.trim_start_matches("day"))?;
+    let html = std::fs::read_to_string(args.positional(2, "page")?)?;
+    let out = match args.option("out") {
+        Some(path) => path.into(),
+        None => runner::puzzle_inputs_dir(args.number_option("year", runner::DEFAULT_YEAR)?),
+    };
+    let mut extracted = samples::extract(day, &html);
+    let written = samples::write(&mut extracted, &out)?;
+    for sample in extracted.samples.iter() {
+        let expected = sample
+            .expected
+            .iter()
+            .map(|(part, answer)| format!("{part} = {answer}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let status = if written.contains(&out.join(&sample.file_name)) {
+            "wrote"
+        } else {
+            "kept"
+        };
+        println!("{status} {}: {expected}", sample.file_name);
+    }
+    for ambiguity in extracted.ambiguities.iter() {
+        println!("check: {ambiguity}");
+    }
+    Ok(())
+}
+
+fn new_command(args: &Args) -> Result<()> {
+    let day = parse_number::<u32>("day", args.positional(1, "day")?.trim_start_matches("day"))?;
+    let year = args.number_option("year", runner::DEFAULT_YEAR)?;
+    for path in scaffold::scaffold(&scaffold::Layout::for_year(year), year, day)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn profile_command(args: &Args) -> Result<()> {
+    if !alloc_profile::is_enabled() {
+        Err("aoc profile needs the alloc-profile feature: cargo run --release --features alloc-profile --bin aoc -- profile ...")?;
+    }
+    let skip = args
+        .option("skip")
+        .map(|skip| skip.split(',').map(runner::find_by_name).collect::<std::result::Result<Vec<_>, _>>())
+        .transpose()?
+        .unwrap_or_default();
+    let solvers = match args.positional(1, "day")? {
+        "all" => runner::SOLVERS.iter().filter(|s| s.year == runner::DEFAULT_YEAR).collect::<Vec<_>>(),
+        _ => {
+            let mut result = Vec::new();
+            for name in args.positional[1..].iter() {
+                match parse_number::<u32>("day", name.trim_start_matches("day")) {
+                    Ok(day) => result.extend(runner::SOLVERS.iter().filter(|s| s.year == runner::DEFAULT_YEAR && s.day == day)),
+                    Err(_) => result.push(runner::find_by_name(name)?),
+                };
+            }
+            result
+        }
+    };
+
+    let mut profile = alloc_profile::Profile::default();
+    for solver in solvers {
+        if skip.iter().any(|s| std::ptr::eq(*s, solver)) {
+            continue;
+        }
+        eprintln!("{}...", solver.name());
+        match alloc_profile::measure(solver, &solver.input()) {
+            Ok(measured) => profile.add(solver, &measured),
+            Err(e) => eprintln!("{}: {}", solver.name(), e.0),
+        };
+    }
+
+    match args.option("compare") {
+        Some(path) => print!("{}", profile.compare(&alloc_profile::Profile::load(path.as_ref())?)),
+        None => print!("{profile}"),
+    };
+    if let Some(path) = args.option("save") {
+        std::fs::write(path, profile.to_tsv())?;
+    }
+    Ok(())
+}
+
+fn run_command(args: &Args) -> Result<()> {
+    let solver = runner::find_by_name(args.positional(1, "solver")?)?;
+    let input = args.option("input").map(|s| s.to_string()).unwrap_or(solver.input());
+    let mode = progress::Mode::try_from(args.option("progress").unwrap_or("auto"))?;
+    let overrides = args
+        .options
+        .iter()
+        .filter(|(name, _)| name != "input" && name != "progress")
+        .map(|(name, value)| Ok((name.clone(), parse_number::<u64>(name, value)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let start = Instant::now();
+    progress::start(&solver.name(), mode);
+    let result = solver.solve(&input, &overrides);
+    progress::finish();
+    println!("{} = {}", solver.name(), result?);
+    eprintln!("took {:?}", start.elapsed());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = Args::new(std::env::args().skip(1)).and_then(|args| match args.positional.first().map(|s| s.as_str()) {
+        Some("run") => run_command(&args),
+        Some("generate") => generate_command(&args),
+        Some("bench") => bench_command(&args),
+        Some("cross-check") => cross_check_command(&args),
+        Some("serve") => serve_command(&args),
+        Some("fetch") => fetch_command(&args),
+        Some("submit") => submit_command(&args),
+        Some("samples") => samples_command(&args),
+        Some("new") => new_command(&args),
+        Some("analyse") => analyse_command(&args),
+        Some("leaderboard") => leaderboard_command(&args),
+        Some("vectors") => vectors_command(&args),
+        Some("profile") => profile_command(&args),
+        _ => Err(USAGE.into()),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e.0);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    fmt::Display,
+    ops::{BitAndAssign, BitOrAssign, SubAssign},
+};
+
+// a width x height grid of booleans, one bit per cell, for walls, visited sets, robot positions and so on
+// cells are stored row by row with no padding, so cell (x, y) is bit y * width + x, and solvers that already
+// number their cells that way can use the *_at methods directly
+// grids of the same size combine a word at a time with |=, &= and -=, and equal grids hash equally
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    // bits past width * height are always zero, so whole word operations don't need masking
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    // anything outside the grid reads as false
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.index(x, y).is_some_and(|i| self.get_at(i))
+    }
+
+    // panics outside the grid, like indexing a vector would
+    pub fn set(&mut self, x: usize, y: usize) {
+        self.set_at(self.index(x, y).expect("out of bounds"));
+    }
+
+    pub fn clear(&mut self, x: usize, y: usize) {
+        self.clear_at(self.index(x, y).expect("out of bounds"));
+    }
+
+    // sets a cell, returning whether it wasn't already set, like HashSet::insert
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        self.insert_at(self.index(x, y).expect("out of bounds"))
+    }
+
+    pub fn get_at(&self, i: usize) -> bool {
+        i < self.len() && self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    pub fn set_at(&mut self, i: usize) {
+        assert!(i < self.len(), "out of bounds");
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn clear_at(&mut self, i: usize) {
+        assert!(i < self.len(), "out of bounds");
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    pub fn insert_at(&mut self, i: usize) -> bool {
+        let was_set = self.get_at(i);
+        self.set_at(i);
+        !was_set
+    }
+
+    pub fn clear_all(&mut self) {
+        self.words.iter_mut().for_each(|w| *w = 0);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    pub fn intersects(&self, other: &BitGrid) -> bool {
+        self.assert_same_size(other);
+        self.words.iter().zip(other.words.iter()).any(|(a, b)| a & b != 0)
+    }
+
+    // indices of the set bits in start..end, in order
+    fn ones_in(&self, start: usize, end: usize) -> impl Iterator<Item = usize> + '_ {
+        let words = if start < end { start / 64..end.div_ceil(64) } else { 0..0 };
+        words.flat_map(move |w| {
+            let mut word = self.words[w];
+            // drop the bits before start and from end on
+            if w == start / 64 {
+                word &= u64::MAX << (start % 64);
+            }
+            if w == end / 64 {
+                word &= (1u64 << (end % 64)).wrapping_sub(1);
+            }
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    w * 64 + bit
+                })
+            })
+        })
+    }
+
+    // the x of every set cell in row y, left to right
+    pub fn row_ones(&self, y: usize) -> impl Iterator<Item = usize> + '_ {
+        let (start, end) = if y < self.height { (y * self.width, (y + 1) * self.width) } else { (0, 0) };
+        self.ones_in(start, end).map(move |i| i - start)
+    }
+
+    // every set cell as (x, y), row by row
+    pub fn ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ones_in(0, self.len()).map(|i| (i % self.width, i / self.width))
+    }
+
+    // every cell moved by (dx, dy), dropping whatever falls off an edge, so e.g.
+    // grid.shifted(1, 0) has a cell set wherever grid has one set just to its left
+    pub fn shifted(&self, dx: i64, dy: i64) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        if dx.unsigned_abs() as usize >= self.width || dy.unsigned_abs() as usize >= self.height {
+            return result;
+        }
+        // moving bit i to bit i + offset does the right thing apart from cells that wrap into the next row
+        let offset = dy * self.width as i64 + dx;
+        let (word_offset, bit_offset) = (offset.unsigned_abs() as usize / 64, offset.unsigned_abs() as usize % 64);
+        for (w, word) in result.words.iter_mut().enumerate() {
+            let source = |k: usize| -> u64 {
+                if offset >= 0 {
+                    w.checked_sub(word_offset + k).map_or(0, |s| self.words[s])
+                } else {
+                    self.words.get(w + word_offset + k).copied().unwrap_or(0)
+                }
+            };
+            *word = match (offset >= 0, bit_offset) {
+                (_, 0) => source(0),
+                (true, b) => source(0) << b | source(1) >> (64 - b),
+                (false, b) => source(0) >> b | source(1) << (64 - b),
+            };
+        }
+        result.mask_tail();
+        if dx != 0 {
+            // clear the columns that wrapped round from the other side
+            let columns = if dx > 0 { 0..dx as usize } else { (self.width as i64 + dx) as usize..self.width };
+            for y in 0..self.height {
+                for x in columns.clone() {
+                    result.clear_at(y * self.width + x);
+                }
+            }
+        }
+        result
+    }
+
+    fn mask_tail(&mut self) {
+        let len = self.len();
+        if !len.is_multiple_of(64) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << (len % 64)) - 1;
+            }
+        }
+    }
+
+    fn assert_same_size(&self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "bit grids must be the same size"
+        );
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words.iter_mut().zip(rhs.words.iter()).for_each(|(a, b)| *a |= b);
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words.iter_mut().zip(rhs.words.iter()).for_each(|(a, b)| *a &= b);
+    }
+}
+
+// clears every cell that's set in rhs
+impl SubAssign<&BitGrid> for BitGrid {
+    fn sub_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words.iter_mut().zip(rhs.words.iter()).for_each(|(a, b)| *a &= !b);
+    }
+}
+
+// # for set, . for clear, one line per row
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> BitGrid {
+        let lines = s.lines().collect::<Vec<_>>();
+        let mut result = BitGrid::new(lines[0].len(), lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    result.set(x, y);
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    pub fn test_get_set() {
+        let mut grid = BitGrid::new(70, 3);
+        assert!(grid.is_empty());
+        assert!(grid.insert(69, 0));
+        assert!(!grid.insert(69, 0));
+        grid.set(0, 1);
+        grid.set(5, 2);
+        assert!(grid.get(69, 0) && grid.get(0, 1) && grid.get(5, 2));
+        assert!(!grid.get(1, 1) && !grid.get(70, 0) && !grid.get(0, 3));
+        assert!(grid.get_at(70));
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(grid.ones().collect::<Vec<_>>(), vec![(69, 0), (0, 1), (5, 2)]);
+        assert_eq!(grid.row_ones(1).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(grid.row_ones(3).count(), 0);
+        grid.clear(0, 1);
+        assert_eq!(grid.count_ones(), 2);
+        grid.clear_all();
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    pub fn test_set_operations() {
+        let a = parse("##..\n.#..\n");
+        let b = parse(".##.\n....\n");
+        let mut union = a.clone();
+        union |= &b;
+        assert_eq!(union.to_string(), "###.\n.#..\n");
+        let mut intersection = a.clone();
+        intersection &= &b;
+        assert_eq!(intersection.to_string(), ".#..\n....\n");
+        let mut difference = a.clone();
+        difference -= &b;
+        assert_eq!(difference.to_string(), "#...\n.#..\n");
+        assert!(a.intersects(&b));
+        assert!(!difference.intersects(&b));
+
+        use std::collections::HashSet;
+        let frames = HashSet::from([a.clone(), b, parse("##..\n.#..\n")]);
+        assert_eq!(frames.len(), 2);
+    }
+
+    #[test]
+    pub fn test_shifted() {
+        let grid = parse("#..#\n.##.\n#...\n");
+        assert_eq!(grid.shifted(1, 0).to_string(), ".#..\n..##\n.#..\n");
+        assert_eq!(grid.shifted(-1, 0).to_string(), "..#.\n##..\n....\n");
+        assert_eq!(grid.shifted(0, 1).to_string(), "....\n#..#\n.##.\n");
+        assert_eq!(grid.shifted(0, -2).to_string(), "#...\n....\n....\n");
+        assert_eq!(grid.shifted(-1, 1).to_string(), "....\n..#.\n##..\n");
+        assert_eq!(grid.shifted(4, 0).to_string(), "....\n....\n....\n");
+
+        // across word boundaries, and checked against moving each cell
+        let mut big = BitGrid::new(67, 5);
+        for i in (0..67 * 5).filter(|i| i % 7 == 0 || i % 11 == 3) {
+            big.set_at(i);
+        }
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1), (30, 2), (-66, -4), (5, -3)] {
+            let mut expected = BitGrid::new(67, 5);
+            for (x, y) in big.ones() {
+                let (x, y) = (x as i64 + dx, y as i64 + dy);
+                if (0..67).contains(&x) && (0..5).contains(&y) {
+                    expected.set(x as usize, y as usize);
+                }
+            }
+            assert_eq!(big.shifted(dx, dy), expected, "({dx}, {dy})");
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, Mul},
+    str::FromStr,
+};
+
+// whether the checked-arithmetic feature is on, in which case the operations here return an Overflow rather than
+// wrapping (in release builds) or panicking (in debug ones), and Wide types are u128
+pub const ENABLED: bool = cfg!(feature = "checked-arithmetic");
+
+// an operation that doesn't fit in its type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    // the solver it happened in, like day07b
+    pub day: &'static str,
+    pub operation: &'static str,
+    pub operands: (String, String),
+    pub type_name: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} {} {} overflows {}",
+            self.day, self.operands.0, self.operation, self.operands.1, self.type_name
+        )
+    }
+}
+
+// the integer types answers get worked out in
+pub trait Integer: Copy + Debug + Display + Eq + Ord + Hash + Default + FromStr + Add<Output = Self> + Mul<Output = Self> {
+    const NAME: &'static str;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn ten() -> Self;
+    // how many decimal digits it has, counting 0 as one
+    fn digits(self) -> u32;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exp)
+                }
+
+                fn ten() -> Self {
+                    10
+                }
+
+                fn digits(self) -> u32 {
+                    self.checked_ilog10().unwrap_or(0) + 1
+                }
+            }
+        )*
+    };
+}
+
+integer!(u32, u64, u128);
+
+// the type a day accumulates into: T normally, and u128 in checked mode so answers too big for T still come out
+pub trait Widen: Sized {
+    type Wide: Integer + From<Self>;
+}
+
+macro_rules! widen {
+    ($($t:ty),*) => {
+        $(
+            impl Widen for $t {
+                #[cfg(feature = "checked-arithmetic")]
+                type Wide = u128;
+                #[cfg(not(feature = "checked-arithmetic"))]
+                type Wide = $t;
+            }
+        )*
+    };
+}
+
+widen!(u32, u64);
+
+pub type Wide<T> = <T as Widen>::Wide;
+
+fn overflow<T: Integer>(day: &'static str, operation: &'static str, a: T, b: T) -> Overflow {
+    Overflow {
+        day,
+        operation,
+        operands: (a.to_string(), b.to_string()),
+        type_name: T::NAME,
+    }
+}
+
+pub fn add<T: Integer>(day: &'static str, a: T, b: T) -> Result<T, Overflow> {
+    match ENABLED {
+        true => a.checked_add(b).ok_or_else(|| overflow(day, "+", a, b)),
+        false => Ok(a + b),
+    }
+}
+
+pub fn mul<T: Integer>(day: &'static str, a: T, b: T) -> Result<T, Overflow> {
+    match ENABLED {
+        true => a.checked_mul(b).ok_or_else(|| overflow(day, "*", a, b)),
+        false => Ok(a * b),
+    }
+}
+
+// the digits of a followed by the digits of b, like day 7's || operator
+pub fn concat<T: Integer>(day: &'static str, a: T, b: T) -> Result<T, Overflow> {
+    let shift = T::ten().checked_pow(b.digits()).ok_or_else(|| overflow(day, "||", a, b))?;
+    match ENABLED {
+        true => a.checked_mul(shift).and_then(|a| a.checked_add(b)).ok_or_else(|| overflow(day, "||", a, b)),
+        false => Ok(a * shift + b),
+    }
+}
+
+pub fn sum<T: Integer>(day: &'static str, values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::default(), |total, value| add(day, total, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_in_range() {
+        assert_eq!(add("test", 2u32, 3), Ok(5));
+        assert_eq!(mul("test", 2u64, 3), Ok(6));
+        assert_eq!(concat("test", 12u64, 345), Ok(12345));
+        assert_eq!(concat("test", 12u64, 0), Ok(120));
+        assert_eq!(sum("test", [1u32, 2, 3]), Ok(6));
+        assert_eq!(sum("test", Vec::<u64>::new()), Ok(0));
+        assert_eq!(0u32.digits(), 1);
+        assert_eq!(u128::MAX.digits(), 39);
+    }
+
+    #[cfg(feature = "checked-arithmetic")]
+    #[test]
+    pub fn test_overflow() {
+        let error = mul("day03a", u32::MAX, 2).unwrap_err();
+        assert_eq!(error.to_string(), "day03a: 4294967295 * 2 overflows u32");
+        assert!(add("test", u64::MAX, 1).is_err());
+        assert!(sum("test", [u32::MAX, 1]).is_err());
+        let error = concat("day07b", u64::MAX / 10, 12).unwrap_err();
+        assert_eq!(error.to_string(), "day07b: 1844674407370955161 || 12 overflows u64");
+        // the numbers themselves fit, but not with that many digits after them
+        assert!(concat("test", 1u128, u128::MAX).is_err());
+        let wide: Wide<u32> = Wide::<u32>::from(u32::MAX) * 4;
+        assert_eq!(wide, 4 * u32::MAX as u128);
+    }
+}
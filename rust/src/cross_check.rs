@@ -0,0 +1,334 @@
+use std::{
+    fmt::Display,
+    mem::discriminant,
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    answer::Answer,
+    generate,
+    runner::{self, Outcome, Solver, Strategy},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<runner::Error> for Error {
+    fn from(value: runner::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<generate::Error> for Error {
+    fn from(value: generate::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// what one strategy made of an input, where any two errors count as the same, since strategies are free to
+// word them differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Answered(Answer),
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl From<Outcome> for Verdict {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Solved { answer, .. } => Verdict::Answered(answer),
+            Outcome::Failed { .. } => Verdict::Failed,
+            Outcome::Panicked => Verdict::Panicked,
+            Outcome::TimedOut => Verdict::TimedOut,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Answered(answer) => write!(f, "{answer}"),
+            Verdict::Failed => write!(f, "(failed)"),
+            Verdict::Panicked => write!(f, "(panicked)"),
+            Verdict::TimedOut => write!(f, "(timed out)"),
+        }
+    }
+}
+
+// a strategy that timed out hasn't said anything, so it can't disagree with the others
+fn disagrees(verdicts: &[Verdict]) -> bool {
+    let finished = verdicts.iter().filter(|v| **v != Verdict::TimedOut).collect::<Vec<_>>();
+    finished.windows(2).any(|pair| pair[0] != pair[1])
+}
+
+// still disagrees, and in the same way, with the same strategies answering, failing and so on
+// otherwise shrinking an input one answer is wrong on tends to end with one every strategy rejects
+fn fails_like(verdicts: &[Verdict], original: &[Verdict]) -> bool {
+    disagrees(verdicts) && verdicts.iter().map(discriminant).eq(original.iter().map(discriminant))
+}
+
+// the smallest input still_fails holds for that can be found by deleting lines and characters within lines
+// it's delta debugging: try deleting big chunks first and smaller ones once those stop working
+// deleting characters can make whole lines unnecessary, so it goes round until neither finds anything
+pub fn shrink(input: &str, mut still_fails: impl FnMut(&str) -> bool) -> String {
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+    let mut lines = input.lines().map(|line| line.to_string()).collect::<Vec<_>>();
+    loop {
+        let before = join(&lines);
+        lines = delete_chunks(lines, |lines| still_fails(&join(lines)));
+        for i in 0..lines.len() {
+            let chars = lines[i].chars().collect::<Vec<_>>();
+            let chars = delete_chunks(chars, |chars| {
+                let mut candidate = lines.clone();
+                candidate[i] = chars.iter().collect();
+                still_fails(&join(&candidate))
+            });
+            lines[i] = chars.into_iter().collect();
+        }
+        if join(&lines) == before {
+            return before;
+        }
+    }
+}
+
+fn delete_chunks<T: Clone>(mut items: Vec<T>, mut still_fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+    while !items.is_empty() {
+        let chunk_len = items.len().div_ceil(chunks);
+        let deleted = (0..items.len()).step_by(chunk_len).find_map(|start| {
+            let mut candidate = items[..start].to_vec();
+            candidate.extend_from_slice(&items[(start + chunk_len).min(items.len())..]);
+            still_fails(&candidate).then_some(candidate)
+        });
+        match deleted {
+            Some(candidate) => {
+                items = candidate;
+                chunks = (chunks - 1).max(2);
+            }
+            // down to deleting one at a time and none of them can go
+            None if chunk_len == 1 => break,
+            None => chunks = (chunks * 2).min(items.len()),
+        };
+    }
+    items
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    // how many generated inputs to try, each with its own seed and a scale that grows with it
+    pub seeds: u64,
+    pub max_scale: usize,
+    // for each strategy on each input, including every attempt while shrinking
+    pub timeout: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seeds: 50,
+            max_scale: 40,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+// an input the strategies for a part don't agree on, already shrunk as far as it will go
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub solver: String,
+    // where the input came from, e.g. the real input or a generated one
+    pub source: String,
+    pub params: Vec<(String, u64)>,
+    pub original_lines: usize,
+    pub input: String,
+    pub verdicts: Vec<(&'static str, Verdict)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} strategies disagree on {}, shrunk from {} lines to {}:",
+            self.solver,
+            self.source,
+            self.original_lines,
+            self.input.lines().count()
+        )?;
+        for (name, value) in self.params.iter() {
+            writeln!(f, "  --{name} {value}")?;
+        }
+        let width = self.verdicts.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, verdict) in self.verdicts.iter() {
+            writeln!(f, "  {name:width$}  {verdict}")?;
+        }
+        writeln!(f, "input:")?;
+        write!(f, "{}", self.input)
+    }
+}
+
+// runs every strategy for one part on one input, written to a file of its own since solvers take paths
+struct Checker<'a> {
+    strategies: Vec<Strategy>,
+    path: &'a Path,
+    params: Vec<u64>,
+    timeout: Duration,
+}
+
+impl Checker<'_> {
+    fn verdicts(&self, input: &str) -> Result<Vec<Verdict>> {
+        std::fs::write(self.path, input)?;
+        let path = self.path.to_string_lossy();
+        Ok(self
+            .strategies
+            .iter()
+            .map(|strategy| strategy.solve_with_timeout(&path, &self.params, self.timeout).into())
+            .collect())
+    }
+}
+
+const MAX_ABANDONED: usize = 4;
+
+// the real input if there is one, then generated ones, stopping at the first disagreement
+pub fn cross_check(solver: &'static Solver, config: &Config) -> Result<Option<Disagreement>> {
+    let strategies = solver.strategies();
+    if strategies.len() < 2 {
+        Err(format!("{} has only one strategy, so there's nothing to check it against", solver.name()))?;
+    }
+    let dir = std::env::temp_dir().join(format!("aoc-cross-check-{}-{}", solver.name(), std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let result = check_inputs(solver, strategies, config, &dir);
+    std::fs::remove_dir_all(&dir)?;
+    result
+}
+
+fn check_inputs(
+    solver: &'static Solver,
+    strategies: Vec<Strategy>,
+    config: &Config,
+    dir: &Path,
+) -> Result<Option<Disagreement>> {
+    // where each came from, the input itself, and any parameters that go with it
+    let mut inputs = Vec::new();
+    let real = runner::puzzle_inputs_dir(solver.year).join(solver.input());
+    if let Ok(input) = std::fs::read_to_string(&real) {
+        inputs.push(("the real input".to_string(), input, Vec::new()));
+    }
+    if let Some(generator) = generate::find(solver.day).filter(|_| solver.year == runner::DEFAULT_YEAR) {
+        let max_scale = config.max_scale.clamp(generator.min_scale, generator.max_scale);
+        for seed in 0..config.seeds {
+            // small inputs first, since a disagreement found on one of those needs less shrinking
+            let scale = generator.min_scale + ((max_scale - generator.min_scale) as u64 * seed / config.seeds.max(1)) as usize;
+            let generated = generator.generate(scale, seed)?;
+            let params = generated.params_for(solver);
+            inputs.push((format!("a generated input (scale {scale}, seed {seed})"), generated.input, params));
+        }
+    }
+
+    let path = dir.join("input.txt");
+    for (source, input, params) in inputs {
+        let checker = Checker {
+            strategies: strategies.clone(),
+            path: &path,
+            params: solver.resolve_params(&params)?,
+            timeout: config.timeout,
+        };
+        let original = checker.verdicts(&input)?;
+        if !disagrees(&original) {
+            continue;
+        }
+        let mut error = None;
+        let shrunk = shrink(&input, |candidate| {
+            // a strategy that timed out is still running, so after a few of those give up shrinking
+            // rather than fight them for the CPU
+            if runner::running_solvers() >= MAX_ABANDONED {
+                return false;
+            }
+            match checker.verdicts(candidate) {
+                Ok(verdicts) => fails_like(&verdicts, &original),
+                Err(e) => {
+                    error = Some(e);
+                    false
+                }
+            }
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        let verdicts = checker.verdicts(&shrunk)?;
+        return Ok(Some(Disagreement {
+            solver: solver.name(),
+            source,
+            params,
+            original_lines: input.lines().count(),
+            input: shrunk,
+            verdicts: strategies.iter().map(|s| s.name).zip(verdicts).collect(),
+        }));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_shrink() {
+        // fails whenever there's a line with a 7 after a line with a 3
+        let fails = |input: &str| {
+            let lines = input.lines().collect::<Vec<_>>();
+            (0..lines.len()).any(|i| lines[i].contains('3') && lines[i + 1..].iter().any(|line| line.contains('7')))
+        };
+        let input = "12\n34 56\n\n78 90\n11\n37\n";
+        assert_eq!(shrink(input, fails), "3\n7\n");
+        assert_eq!(shrink("1\n2\n", |_| true), "");
+    }
+
+    #[test]
+    pub fn test_fails_like() {
+        let answered = |x: u64| Verdict::Answered(x.into());
+        let original = [answered(1), answered(2)];
+        assert!(fails_like(&[answered(3), answered(4)], &original));
+        assert!(!fails_like(&[answered(3), answered(3)], &original));
+        assert!(!fails_like(&[answered(3), Verdict::Failed], &original));
+        assert!(!disagrees(&[Verdict::Failed, Verdict::Failed]));
+        assert!(!disagrees(&[answered(1), Verdict::TimedOut, answered(1)]));
+    }
+
+    #[test]
+    pub fn test_strategies_agree() {
+        let config = Config {
+            seeds: 10,
+            max_scale: 30,
+            timeout: Duration::from_secs(60),
+        };
+        for solver in runner::SOLVERS.iter().filter(|s| s.strategies().len() > 1) {
+            if let Some(disagreement) = cross_check(solver, &config).unwrap() {
+                panic!("{disagreement}");
+            }
+        }
+        assert!(cross_check(runner::find_by_name("day01a").unwrap(), &config).is_err());
+    }
+}
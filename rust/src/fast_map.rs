@@ -0,0 +1,291 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, Hasher},
+    ops::RangeInclusive,
+};
+
+// the hasher rustc uses internally, much faster than SipHash on small keys
+// it makes no attempt to resist collisions chosen by an attacker, which puzzle inputs aren't
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut rest = [0u8; 8];
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            rest[..remainder.len()].copy_from_slice(remainder);
+            self.add(u64::from_le_bytes(rest));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type FastHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FxHasher>>;
+pub type FastHashSet<K> = HashSet<K, BuildHasherDefault<FxHasher>>;
+
+// a map from keys in 0..capacity to values, stored in a vector indexed by key
+// for when the keys are small enough that a slot for every possible one is cheaper than hashing
+#[derive(Debug, Clone)]
+pub struct DenseMap<V> {
+    slots: Vec<Option<V>>,
+    len: usize,
+}
+
+impl<V> DenseMap<V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains_key(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn get(&self, key: usize) -> Option<&V> {
+        self.slots.get(key)?.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut V> {
+        self.slots.get_mut(key)?.as_mut()
+    }
+
+    // panics if key is past the capacity, like indexing a vector would
+    pub fn insert(&mut self, key: usize, value: V) -> Option<V> {
+        let previous = self.slots[key].replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    pub fn get_or_insert_with(&mut self, key: usize, f: impl FnOnce() -> V) -> &mut V {
+        if self.slots[key].is_none() {
+            self.len += 1;
+        }
+        self.slots[key].get_or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, key: usize) -> Option<V> {
+        let previous = self.slots.get_mut(key)?.take();
+        if previous.is_some() {
+            self.len -= 1;
+        }
+        previous
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    // in key order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &V)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(key, slot)| slot.as_ref().map(|value| (key, value)))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.slots.iter().flatten()
+    }
+}
+
+impl<V: Default> DenseMap<V> {
+    pub fn entry_or_default(&mut self, key: usize) -> &mut V {
+        self.get_or_insert_with(key, V::default)
+    }
+}
+
+// packs sequences of up to len values from a small range into a single dense key, e.g. four price
+// changes in -9..=9 into one of 20^4, so they can index a DenseMap or a plain vector
+// shorter sequences get their own keys, as if padded at the front with a value outside the range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packer {
+    min: i64,
+    // values in the range, plus one for padding
+    base: usize,
+    len: usize,
+    key_count: usize,
+}
+
+impl Packer {
+    pub fn new(range: RangeInclusive<i64>, len: usize) -> Self {
+        let base = (range.end() - range.start() + 2) as usize;
+        Self {
+            min: *range.start(),
+            base,
+            len,
+            key_count: base.checked_pow(len as u32).expect("too many keys to pack into a usize"),
+        }
+    }
+
+    // every key is below this
+    pub fn key_count(&self) -> usize {
+        self.key_count
+    }
+
+    fn digit(&self, value: i64) -> Option<usize> {
+        match value - self.min {
+            d if d < 0 || d as usize >= self.base - 1 => None,
+            d => Some(d as usize + 1),
+        }
+    }
+
+    // None if there are too many values or any is out of range
+    pub fn pack(&self, values: &[i64]) -> Option<usize> {
+        if values.len() > self.len {
+            return None;
+        }
+        values
+            .iter()
+            .try_fold(0, |key, value| Some(key * self.base + self.digit(*value)?))
+    }
+
+    // the key for the last len values once value is appended to the sequence key packs,
+    // which makes a sliding window over a stream of values O(1) per step
+    pub fn push(&self, key: usize, value: i64) -> Option<usize> {
+        Some((key * self.base + self.digit(value)?) % self.key_count)
+    }
+
+    pub fn unpack(&self, mut key: usize) -> Vec<i64> {
+        let mut result = Vec::with_capacity(self.len);
+        while key > 0 {
+            result.push((key % self.base) as i64 - 1 + self.min);
+            key /= self.base;
+        }
+        result.reverse();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_fx_hasher() {
+        let hash = |bytes: &[u8]| {
+            let mut hasher = FxHasher::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+        assert_eq!(hash(b"towel"), hash(b"towel"));
+        assert_ne!(hash(b"towel"), hash(b"towels"));
+        assert_ne!(hash(b"abcdefgh1"), hash(b"abcdefgh2"));
+
+        let mut map = FastHashMap::default();
+        for i in 0..1000u64 {
+            *map.entry(i % 10).or_insert(0) += i;
+        }
+        assert_eq!(map.len(), 10);
+        assert_eq!(map[&3], (0..100).map(|i| i * 10 + 3).sum());
+    }
+
+    #[test]
+    pub fn test_dense_map() {
+        let mut map = DenseMap::new(10);
+        assert!(map.is_empty());
+        assert_eq!(map.insert(7, "seven"), None);
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.insert(7, "SEVEN"), Some("seven"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(7), Some(&"SEVEN"));
+        assert_eq!(map.get(3), None);
+        assert_eq!(map.get(30), None);
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![(2, &"two"), (7, &"SEVEN")]);
+        assert_eq!(map.remove(2), Some("two"));
+        assert_eq!(map.remove(2), None);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![7]);
+        map.clear();
+        assert!(map.is_empty());
+
+        let mut counts = DenseMap::<u64>::new(4);
+        *counts.entry_or_default(1) += 5;
+        *counts.entry_or_default(1) += 2;
+        assert_eq!(counts.values().collect::<Vec<_>>(), vec![&7]);
+        assert_eq!(counts.len(), 1);
+    }
+
+    #[test]
+    pub fn test_packer() {
+        let packer = Packer::new(-9..=9, 4);
+        assert_eq!(packer.key_count(), 20usize.pow(4));
+        let keys = [vec![], vec![-9], vec![9], vec![0, 0], vec![-2, 1, -1, 3], vec![9, 9, 9, 9]]
+            .into_iter()
+            .map(|values| {
+                let key = packer.pack(&values).unwrap();
+                assert!(key < packer.key_count());
+                assert_eq!(packer.unpack(key), values);
+                key
+            })
+            .collect::<FastHashSet<_>>();
+        assert_eq!(keys.len(), 6);
+        assert_eq!(packer.pack(&[10]), None);
+        assert_eq!(packer.pack(&[1, 2, 3, 4, 5]), None);
+
+        // sliding a window along a stream gives the same keys as packing each window
+        let stream = [3, -1, 4, -1, -5, 9, -2, 6];
+        let mut key = 0;
+        for (i, value) in stream.iter().enumerate() {
+            key = packer.push(key, *value).unwrap();
+            assert_eq!(Some(key), packer.pack(&stream[(i + 1).saturating_sub(4)..=i]));
+        }
+    }
+}
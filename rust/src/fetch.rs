@@ -0,0 +1,231 @@
+use std::{
+    env,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::runner;
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/jeffreythomasprice/advent-of-code-2024";
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: String,
+    pub year: u32,
+    pub inputs_dir: PathBuf,
+    // minimum time between two requests to the server
+    pub min_interval: Duration,
+}
+
+impl Config {
+    // AOC_SESSION is the session cookie from a logged in browser, AOC_BASE_URL overrides the server
+    pub fn from_env(year: u32) -> Result<Self> {
+        Ok(Self {
+            base_url: env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string()),
+            session: env::var("AOC_SESSION").map_err(|_| "AOC_SESSION must be set to your adventofcode.com session cookie")?,
+            year,
+            inputs_dir: runner::puzzle_inputs_dir(year),
+            min_interval: Duration::from_secs(5),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    // the file was already there, nothing was requested
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// a client for adventofcode.com that spaces out its requests
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            last_request: None,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn wait_for_rate_limit(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.config.min_interval {
+                thread::sleep(self.config.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{path}", self.config.base_url.trim_end_matches('/'), self.config.year)
+    }
+
+    // returns the status code and body, non-2xx statuses aren't errors here so callers can interpret them
+    pub fn get(&mut self, path: &str) -> Result<(u16, String)> {
+        self.wait_for_rate_limit();
+        let request = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &format!("session={}", self.config.session));
+        read_response(request.call())
+    }
+
+    pub fn post_form(&mut self, path: &str, fields: &[(&str, &str)]) -> Result<(u16, String)> {
+        self.wait_for_rate_limit();
+        let request = self
+            .agent
+            .post(&self.url(path))
+            .set("Cookie", &format!("session={}", self.config.session));
+        read_response(request.send_form(fields))
+    }
+
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.config.inputs_dir.join(format!("day{day:02}.txt"))
+    }
+
+    // downloads the input for a day, unless it's already been saved
+    pub fn fetch_input(&mut self, day: u32) -> Result<Fetched> {
+        if !(1..=25).contains(&day) {
+            Err(format!("no such day: {day}"))?;
+        }
+        let path = self.input_path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let (status, body) = self.get(&format!("/day/{day}/input"))?;
+        match status {
+            200 => {}
+            400 => Err(format!("day {day}: server rejected the session cookie: {}", body.trim()))?,
+            404 => Err(format!("day {day}: input not found, is the puzzle unlocked yet? {}", body.trim()))?,
+            _ => Err(format!("day {day}: unexpected status {status}: {}", body.trim()))?,
+        };
+
+        // write somewhere else first so a failure never leaves a partial input behind
+        std::fs::create_dir_all(&self.config.inputs_dir)?;
+        let partial = path.with_extension("txt.partial");
+        std::fs::write(&partial, body)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+fn read_response(response: std::result::Result<ureq::Response, ureq::Error>) -> Result<(u16, String)> {
+    let response = match response {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(e)) => Err(format!("request failed: {e}"))?,
+    };
+    let status = response.status();
+    let body = response.into_string()?;
+    Ok((status, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn config(url: &str, name: &str) -> Config {
+        let inputs_dir = env::temp_dir().join(format!("aoc-fetch-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&inputs_dir);
+        Config {
+            base_url: url.to_string(),
+            session: "abc123".to_string(),
+            year: 2024,
+            inputs_dir,
+            min_interval: Duration::from_millis(0),
+        }
+    }
+
+    #[test]
+    pub fn test_download_then_cache() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2024/day/3/input" => (200, "mul(2,4)\n".to_string()),
+            _ => (404, "not found".to_string()),
+        });
+        let mut client = Client::new(config(&server.url, "cache"));
+
+        let fetched = client.fetch_input(3).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(client.input_path(3)));
+        assert_eq!(std::fs::read_to_string(client.input_path(3)).unwrap(), "mul(2,4)\n");
+        assert_eq!(client.fetch_input(3).unwrap(), Fetched::Cached(client.input_path(3)));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].body, "");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+        std::fs::remove_dir_all(&client.config().inputs_dir).unwrap();
+    }
+
+    #[test]
+    pub fn test_errors_leave_nothing_behind() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2024/day/1/input" => (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+            _ => (404, "Please don't repeatedly request this endpoint before it unlocks!".to_string()),
+        });
+        let mut client = Client::new(config(&server.url, "errors"));
+        assert!(client.fetch_input(1).unwrap_err().0.contains("session cookie"));
+        assert!(client.fetch_input(25).unwrap_err().0.contains("unlocked"));
+        assert!(client.fetch_input(26).is_err());
+        assert!(!client.input_path(1).exists());
+        assert!(!client.input_path(25).exists());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    pub fn test_rate_limit() {
+        let server = MockServer::start(|_| (200, "1\n".to_string()));
+        let mut config = config(&server.url, "rate");
+        config.min_interval = Duration::from_millis(200);
+        let mut client = Client::new(config);
+        let start = Instant::now();
+        client.fetch_input(1).unwrap();
+        client.fetch_input(2).unwrap();
+        client.fetch_input(3).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        std::fs::remove_dir_all(&client.config().inputs_dir).unwrap();
+    }
+}
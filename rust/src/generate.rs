@@ -0,0 +1,789 @@
+use std::collections::{HashSet, VecDeque};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::runner::Solver;
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+// a puzzle input plus any solver parameters that have to agree with it, e.g. the grid size for day 18
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(String, u64)>,
+}
+
+impl Generated {
+    fn new(input: String) -> Self {
+        Self {
+            input,
+            params: Vec::new(),
+        }
+    }
+
+    fn with_param(mut self, name: &str, value: u64) -> Self {
+        self.params.push((name.to_string(), value));
+        self
+    }
+
+    // both parts of a day share a generator, but not always the same parameters
+    pub fn params_for(&self, solver: &Solver) -> Vec<(String, u64)> {
+        self.params
+            .iter()
+            .filter(|(name, _)| solver.params.iter().any(|p| p.name == name))
+            .cloned()
+            .collect()
+    }
+}
+
+pub struct Generator {
+    pub day: u32,
+    // what the scale argument counts for this day
+    pub unit: &'static str,
+    pub min_scale: usize,
+    pub max_scale: usize,
+    generate: fn(&mut StdRng, usize) -> Generated,
+}
+
+impl Generator {
+    // the same day, scale, and seed always produce the same input
+    pub fn generate(&self, scale: usize, seed: u64) -> Result<Generated> {
+        if scale < self.min_scale || scale > self.max_scale {
+            Err(format!(
+                "day {} scale must be in {}..={} {}, got {scale}",
+                self.day, self.min_scale, self.max_scale, self.unit
+            ))?;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        Ok((self.generate)(&mut rng, scale))
+    }
+}
+
+#[rustfmt::skip]
+pub static GENERATORS: &[Generator] = &[
+    Generator { day: 1, unit: "location id pairs", min_scale: 1, max_scale: usize::MAX, generate: day01 },
+    Generator { day: 2, unit: "reports", min_scale: 1, max_scale: usize::MAX, generate: day02 },
+    Generator { day: 3, unit: "mul instructions", min_scale: 1, max_scale: usize::MAX, generate: day03 },
+    Generator { day: 4, unit: "grid side length", min_scale: 4, max_scale: 100_000, generate: day04 },
+    Generator { day: 5, unit: "page ordering rules", min_scale: 10, max_scale: usize::MAX, generate: day05 },
+    Generator { day: 6, unit: "grid side length", min_scale: 3, max_scale: 100_000, generate: day06 },
+    Generator { day: 7, unit: "equations", min_scale: 1, max_scale: usize::MAX, generate: day07 },
+    Generator { day: 8, unit: "grid side length", min_scale: 2, max_scale: 100_000, generate: day08 },
+    Generator { day: 9, unit: "disk map digits", min_scale: 1, max_scale: usize::MAX, generate: day09 },
+    Generator { day: 10, unit: "grid side length", min_scale: 2, max_scale: 100_000, generate: day10 },
+    Generator { day: 11, unit: "stones", min_scale: 1, max_scale: usize::MAX, generate: day11 },
+    Generator { day: 12, unit: "grid side length", min_scale: 1, max_scale: 100_000, generate: day12 },
+    Generator { day: 13, unit: "claw machines", min_scale: 1, max_scale: usize::MAX, generate: day13 },
+    Generator { day: 14, unit: "robots", min_scale: 1, max_scale: usize::MAX, generate: day14 },
+    Generator { day: 15, unit: "grid side length", min_scale: 5, max_scale: 100_000, generate: day15 },
+    Generator { day: 16, unit: "maze side length", min_scale: 5, max_scale: 100_000, generate: day16 },
+    Generator { day: 17, unit: "octal digits in register A", min_scale: 1, max_scale: 21, generate: day17 },
+    Generator { day: 18, unit: "grid side length", min_scale: 3, max_scale: 100_000, generate: day18 },
+    Generator { day: 19, unit: "designs", min_scale: 1, max_scale: usize::MAX, generate: day19 },
+    Generator { day: 20, unit: "racetrack side length", min_scale: 5, max_scale: 100_000, generate: day20 },
+    Generator { day: 21, unit: "door codes", min_scale: 1, max_scale: usize::MAX, generate: day21 },
+    Generator { day: 22, unit: "buyers", min_scale: 1, max_scale: usize::MAX, generate: day22 },
+    Generator { day: 23, unit: "computers", min_scale: 3, max_scale: usize::MAX, generate: day23 },
+    Generator { day: 24, unit: "adder bits", min_scale: 1, max_scale: 63, generate: day24 },
+    Generator { day: 25, unit: "locks and keys", min_scale: 1, max_scale: usize::MAX, generate: day25 },
+];
+
+pub fn find(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+fn grid_to_string(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+fn day01(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut left = Vec::with_capacity(scale);
+    let mut result = String::new();
+    for _ in 0..scale {
+        let l = rng.gen_range(10000..100000u32);
+        left.push(l);
+        // reuse some ids from the left list so the similarity score has something to count
+        let r = if rng.gen_bool(0.3) {
+            *left.choose(rng).unwrap()
+        } else {
+            rng.gen_range(10000..100000u32)
+        };
+        result.push_str(&format!("{l}   {r}\n"));
+    }
+    Generated::new(result)
+}
+
+fn day02(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut result = String::new();
+    for _ in 0..scale {
+        let len = rng.gen_range(5..=8);
+        let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let mut level: i32 = rng.gen_range(25..75);
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            level += direction * rng.gen_range(1..=3);
+        }
+        // break some of the reports
+        if rng.gen_bool(0.3) {
+            let i = rng.gen_range(0..len);
+            levels[i] = rng.gen_range(1..100);
+        }
+        result.push_str(
+            &levels
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        result.push('\n');
+    }
+    Generated::new(result)
+}
+
+fn day03(rng: &mut StdRng, scale: usize) -> Generated {
+    const JUNK: &[&str] = &[
+        "!", "@", "#", "$", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", "?", ",", "'", "+", "-", "/", "~", ":",
+        ";", " ", "what()", "from()", "who()", "how(", "where()", "why()", "select()", "mul[3,7]", "mul(32,64]", "mul ( 2 , 4 )",
+        "mul(4*", "mul(6,9!", "?(12,34)", "mul(1234,5)", "do_not_mul(",
+    ];
+    let mut result = String::new();
+    let mut line_len = 0;
+    let mut count = 0;
+    while count < scale {
+        let token = match rng.gen_range(0..100) {
+            0..=29 => {
+                count += 1;
+                format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000))
+            }
+            30..=32 => "do()".to_string(),
+            33..=35 => "don't()".to_string(),
+            _ => JUNK.choose(rng).unwrap().to_string(),
+        };
+        line_len += token.len();
+        result.push_str(&token);
+        if line_len > 3000 {
+            result.push('\n');
+            line_len = 0;
+        }
+    }
+    result.push('\n');
+    Generated::new(result)
+}
+
+fn day04(rng: &mut StdRng, scale: usize) -> Generated {
+    let grid = (0..scale)
+        .map(|_| (0..scale).map(|_| *['X', 'M', 'A', 'S'].choose(rng).unwrap()).collect())
+        .collect::<Vec<Vec<char>>>();
+    Generated::new(grid_to_string(&grid))
+}
+
+fn day05(rng: &mut StdRng, scale: usize) -> Generated {
+    // every pair of pages gets a rule, so pick the biggest set of pages that fits in the requested number of rules
+    let mut pages = 5;
+    while (pages + 1) * pages / 2 <= scale {
+        pages += 1;
+    }
+    let mut ids = (10..(10 + pages as u32 * 2)).collect::<Vec<_>>();
+    ids.shuffle(rng);
+    ids.truncate(pages);
+    // ids is now the one true order
+
+    let mut rules = Vec::with_capacity(pages * (pages - 1) / 2);
+    for i in 0..pages {
+        for j in (i + 1)..pages {
+            rules.push(format!("{}|{}", ids[i], ids[j]));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut result = rules.join("\n");
+    result.push_str("\n\n");
+    let update_count = (scale / 6).max(6);
+    for _ in 0..update_count {
+        let len = rng.gen_range(2..=(pages.min(23) - 1) / 2) * 2 + 1;
+        let mut indices = (0..pages).collect::<Vec<_>>();
+        indices.shuffle(rng);
+        indices.truncate(len);
+        // roughly half the updates are already in the right order
+        if rng.gen_bool(0.5) {
+            indices.sort();
+        }
+        result.push_str(
+            &indices
+                .iter()
+                .map(|i| ids[*i].to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        result.push('\n');
+    }
+    Generated::new(result)
+}
+
+fn day06(rng: &mut StdRng, scale: usize) -> Generated {
+    // keep rolling maps until the guard walks off the edge instead of getting stuck in a loop, because part 1 never finishes otherwise
+    loop {
+        let mut grid = (0..scale)
+            .map(|_| (0..scale).map(|_| if rng.gen_bool(0.1) { '#' } else { '.' }).collect())
+            .collect::<Vec<Vec<char>>>();
+        let (x, y) = (rng.gen_range(0..scale), rng.gen_range(0..scale));
+        grid[y][x] = '^';
+
+        let (mut x, mut y) = (x as i64, y as i64);
+        let (mut dx, mut dy) = (0i64, -1i64);
+        let mut seen = HashSet::new();
+        let escapes = loop {
+            if !seen.insert((x, y, dx, dy)) {
+                break false;
+            }
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || ny < 0 || nx >= scale as i64 || ny >= scale as i64 {
+                break true;
+            }
+            if grid[ny as usize][nx as usize] == '#' {
+                (dx, dy) = (-dy, dx);
+            } else {
+                (x, y) = (nx, ny);
+            }
+        };
+        if escapes {
+            return Generated::new(grid_to_string(&grid));
+        }
+    }
+}
+
+fn day07(rng: &mut StdRng, scale: usize) -> Generated {
+    // at most 9 two digit numbers keeps every combination of operators well inside a u64
+    let mut result = String::new();
+    for _ in 0..scale {
+        let len = rng.gen_range(2..=9);
+        let values = (0..len).map(|_| rng.gen_range(1..100u64)).collect::<Vec<_>>();
+        let mut answer = values[0];
+        for value in values.iter().skip(1) {
+            answer = match rng.gen_range(0..3) {
+                0 => answer + value,
+                1 => answer * value,
+                _ => format!("{answer}{value}").parse().unwrap(),
+            };
+        }
+        // some equations shouldn't be solvable
+        if rng.gen_bool(0.4) {
+            answer += 1;
+        }
+        result.push_str(&format!(
+            "{answer}: {}\n",
+            values
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+    }
+    Generated::new(result)
+}
+
+fn day08(rng: &mut StdRng, scale: usize) -> Generated {
+    let frequencies = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    let grid = (0..scale)
+        .map(|_| {
+            (0..scale)
+                .map(|_| {
+                    if rng.gen_bool(0.025) {
+                        *frequencies.choose(rng).unwrap()
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<char>>>();
+    Generated::new(grid_to_string(&grid))
+}
+
+fn day09(rng: &mut StdRng, scale: usize) -> Generated {
+    // the map has to start and end with a file
+    let len = scale | 1;
+    let mut result = (0..len)
+        .map(|i| {
+            let digit = if i % 2 == 0 { rng.gen_range(1..=9) } else { rng.gen_range(0..=9) };
+            char::from_digit(digit, 10).unwrap()
+        })
+        .collect::<String>();
+    result.push('\n');
+    Generated::new(result)
+}
+
+fn day10(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut grid = (0..scale)
+        .map(|_| (0..scale).map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap()).collect())
+        .collect::<Vec<Vec<char>>>();
+    // random noise has almost no trails, so carve some
+    for _ in 0..((scale * scale) / 20).max(1) {
+        let (mut x, mut y) = (rng.gen_range(0..scale), rng.gen_range(0..scale));
+        for height in 0..10 {
+            grid[y][x] = char::from_digit(height, 10).unwrap();
+            match rng.gen_range(0..4) {
+                0 if x > 0 => x -= 1,
+                1 if x + 1 < scale => x += 1,
+                2 if y > 0 => y -= 1,
+                3 if y + 1 < scale => y += 1,
+                _ => break,
+            };
+        }
+    }
+    Generated::new(grid_to_string(&grid))
+}
+
+fn day11(rng: &mut StdRng, scale: usize) -> Generated {
+    let stones = (0..scale).map(|_| rng.gen_range(0..10_000_000u64).to_string()).collect::<Vec<_>>();
+    Generated::new(stones.join(" ") + "\n")
+}
+
+fn day12(rng: &mut StdRng, scale: usize) -> Generated {
+    // copy neighbors most of the time so we get regions instead of single cells
+    let mut grid: Vec<Vec<char>> = Vec::with_capacity(scale);
+    for y in 0..scale {
+        let mut row: Vec<char> = Vec::with_capacity(scale);
+        for x in 0..scale {
+            let c = match rng.gen_range(0..10) {
+                0..=3 if x > 0 => row[x - 1],
+                4..=7 if y > 0 => grid[y - 1][x],
+                _ => rng.gen_range('A'..='Z'),
+            };
+            row.push(c);
+        }
+        grid.push(row);
+    }
+    Generated::new(grid_to_string(&grid))
+}
+
+fn day13(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut machines = Vec::with_capacity(scale);
+    for _ in 0..scale {
+        let (ax, ay) = (rng.gen_range(10..100i64), rng.gen_range(10..100i64));
+        let (bx, by) = (rng.gen_range(10..100i64), rng.gen_range(10..100i64));
+        let (a, b) = (rng.gen_range(0..=100i64), rng.gen_range(0..=100i64));
+        let (mut px, mut py) = (a * ax + b * bx, a * ay + b * by);
+        // some prizes shouldn't be reachable
+        if rng.gen_bool(0.4) {
+            px += rng.gen_range(1..50);
+            py += rng.gen_range(1..50);
+        }
+        machines.push(format!(
+            "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n"
+        ));
+    }
+    Generated::new(machines.join("\n"))
+}
+
+fn day14(rng: &mut StdRng, scale: usize) -> Generated {
+    let (width, height) = (101, 103);
+    let mut result = String::new();
+    for _ in 0..scale {
+        result.push_str(&format!(
+            "p={},{} v={},{}\n",
+            rng.gen_range(0..width),
+            rng.gen_range(0..height),
+            rng.gen_range(-99..100),
+            rng.gen_range(-99..100)
+        ));
+    }
+    Generated::new(result)
+        .with_param("width", width as u64)
+        .with_param("height", height as u64)
+}
+
+fn day15(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut grid = (0..scale)
+        .map(|y| {
+            (0..scale)
+                .map(|x| {
+                    if x == 0 || y == 0 || x + 1 == scale || y + 1 == scale {
+                        '#'
+                    } else {
+                        match rng.gen_range(0..100) {
+                            0..=4 => '#',
+                            5..=24 => 'O',
+                            _ => '.',
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<char>>>();
+    grid[rng.gen_range(1..(scale - 1))][rng.gen_range(1..(scale - 1))] = '@';
+
+    let mut result = grid_to_string(&grid);
+    result.push('\n');
+    let moves = (0..(8 * scale * scale))
+        .map(|_| *['^', 'v', '<', '>'].choose(rng).unwrap())
+        .collect::<Vec<_>>();
+    for line in moves.chunks(1000) {
+        result.extend(line.iter());
+        result.push('\n');
+    }
+    Generated::new(result)
+}
+
+// a maze with walls on even coordinates and passages on odd ones, carved with a randomized depth first search
+fn maze(rng: &mut StdRng, size: usize) -> Vec<Vec<char>> {
+    let mut grid = vec![vec!['#'; size]; size];
+    let mut stack = vec![(1, 1)];
+    grid[1][1] = '.';
+    while let Some(&(x, y)) = stack.last() {
+        let mut neighbors = [(2i64, 0i64), (-2, 0), (0, 2), (0, -2)]
+            .iter()
+            .map(|(dx, dy)| (x as i64 + dx, y as i64 + dy))
+            .filter(|(nx, ny)| *nx > 0 && *ny > 0 && *nx < size as i64 - 1 && *ny < size as i64 - 1)
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+            .filter(|(nx, ny)| grid[*ny][*nx] == '#')
+            .collect::<Vec<_>>();
+        if neighbors.is_empty() {
+            stack.pop();
+        } else {
+            neighbors.shuffle(rng);
+            let (nx, ny) = neighbors[0];
+            grid[(y + ny) / 2][(x + nx) / 2] = '.';
+            grid[ny][nx] = '.';
+            stack.push((nx, ny));
+        }
+    }
+    grid
+}
+
+fn day16(rng: &mut StdRng, scale: usize) -> Generated {
+    let size = scale | 1;
+    let mut grid = maze(rng, size);
+    // knock out some walls so there's more than one way through
+    for (y, row) in grid.iter_mut().enumerate().take(size - 1).skip(1) {
+        for (x, cell) in row.iter_mut().enumerate().take(size - 1).skip(1) {
+            if *cell == '#' && (x + y) % 2 == 1 && rng.gen_bool(0.1) {
+                *cell = '.';
+            }
+        }
+    }
+    grid[size - 2][1] = 'S';
+    grid[1][size - 2] = 'E';
+    Generated::new(grid_to_string(&grid))
+}
+
+fn day17(rng: &mut StdRng, scale: usize) -> Generated {
+    // same shape as the real program, shift a right by 3 and output one digit per loop
+    let program = [2, 4, 1, rng.gen_range(0..8), 7, 5, 4, 0, 0, 3, 1, rng.gen_range(0..8), 5, 5, 3, 0];
+    let mut a: u64 = rng.gen_range(1..8);
+    for _ in 1..scale {
+        a = (a << 3) | rng.gen_range(0..8);
+    }
+    Generated::new(format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    ))
+}
+
+fn day18(rng: &mut StdRng, scale: usize) -> Generated {
+    // the first bytes to fall are walls across every other row, with a gap at alternate ends, so the way out
+    // winds back and forth through the whole grid before the rest fall at random
+    let is_wall = |(x, y): (usize, usize)| y % 2 == 1 && x != if y % 4 == 1 { scale - 1 } else { 0 };
+    let (mut walls, mut rest): (Vec<_>, Vec<_>) = (0..(scale * scale))
+        .map(|i| (i % scale, i / scale))
+        .filter(|p| *p != (0, 0) && *p != (scale - 1, scale - 1))
+        .partition(|p| is_wall(*p));
+    walls.shuffle(rng);
+    rest.shuffle(rng);
+    let bytes = walls.into_iter().chain(rest).collect::<Vec<_>>();
+
+    // part 1 needs the exit to still be reachable after the first count bytes fall
+    let is_reachable = |count: usize| {
+        let mut blocked = vec![false; scale * scale];
+        for (x, y) in bytes.iter().take(count) {
+            blocked[y * scale + x] = true;
+        }
+        let mut visited = vec![false; scale * scale];
+        let mut queue = VecDeque::from([(0usize, 0usize)]);
+        visited[0] = true;
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) == (scale - 1, scale - 1) {
+                return true;
+            }
+            for (nx, ny) in [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)] {
+                if nx < scale && ny < scale && !blocked[ny * scale + nx] && !visited[ny * scale + nx] {
+                    visited[ny * scale + nx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        false
+    };
+    let mut count = bytes.len() / 5;
+    while count > 0 && !is_reachable(count) {
+        count /= 2;
+    }
+
+    let mut result = String::new();
+    for (x, y) in bytes.iter() {
+        result.push_str(&format!("{x},{y}\n"));
+    }
+    Generated::new(result)
+        .with_param("width", scale as u64)
+        .with_param("height", scale as u64)
+        .with_param("count", count as u64)
+}
+
+fn day19(rng: &mut StdRng, scale: usize) -> Generated {
+    // no single g towel, so some designs are impossible
+    let mut towels = vec!["w".to_string(), "u".to_string(), "b".to_string(), "r".to_string()];
+    let mut seen = towels.iter().cloned().collect::<HashSet<_>>();
+    while towels.len() < 60 {
+        let len = rng.gen_range(2..=8);
+        let towel = (0..len).map(|_| *['w', 'u', 'b', 'r', 'g'].choose(rng).unwrap()).collect::<String>();
+        if seen.insert(towel.clone()) {
+            towels.push(towel);
+        }
+    }
+    towels.shuffle(rng);
+
+    let mut result = towels.join(", ");
+    result.push_str("\n\n");
+    for _ in 0..scale {
+        // designs are capped at 40 stripes so the number of arrangements still fits in a usize
+        let len = rng.gen_range(20..=40);
+        let mut design = String::new();
+        while design.len() < len {
+            design.push_str(towels.choose(rng).unwrap());
+        }
+        design.truncate(len);
+        if rng.gen_bool(0.3) {
+            let i = rng.gen_range(0..len);
+            design.replace_range(i..(i + 1), "g");
+        }
+        result.push_str(&design);
+        result.push('\n');
+    }
+    Generated::new(result)
+}
+
+fn day20(rng: &mut StdRng, scale: usize) -> Generated {
+    // the race track is the only path through a maze, every other passage gets walled back up
+    let size = scale | 1;
+    let grid = maze(rng, size);
+    let (start, end) = ((1, size - 2), (size - 2, 1));
+    let mut previous = vec![None; size * size];
+    let mut queue = VecDeque::from([start]);
+    previous[start.1 * size + start.0] = Some(start);
+    while let Some((x, y)) = queue.pop_front() {
+        for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if grid[ny][nx] == '.' && previous[ny * size + nx].is_none() {
+                previous[ny * size + nx] = Some((x, y));
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    let mut track = vec![vec!['#'; size]; size];
+    let mut p = end;
+    while p != start {
+        track[p.1][p.0] = '.';
+        p = previous[p.1 * size + p.0].unwrap();
+    }
+    track[start.1][start.0] = 'S';
+    track[end.1][end.0] = 'E';
+    Generated::new(grid_to_string(&track))
+}
+
+fn day21(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut result = String::new();
+    for _ in 0..scale {
+        result.push_str(&format!("{:03}A\n", rng.gen_range(0..1000)));
+    }
+    Generated::new(result)
+}
+
+fn day22(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut result = String::new();
+    for _ in 0..scale {
+        result.push_str(&format!("{}\n", rng.gen_range(1..16777216u64)));
+    }
+    Generated::new(result)
+}
+
+fn day23(rng: &mut StdRng, scale: usize) -> Generated {
+    // two letter names like the real input, then longer ones once those run out
+    let name = |mut i: usize| {
+        let mut result = String::new();
+        loop {
+            result.insert(0, (b'a' + (i % 26) as u8) as char);
+            i /= 26;
+            if i == 0 && result.len() >= 2 {
+                break;
+            }
+        }
+        result
+    };
+    let mut names = (0..scale).map(name).collect::<Vec<_>>();
+    names.shuffle(rng);
+
+    let mut edges = HashSet::new();
+    let add_edge = |a: usize, b: usize, edges: &mut HashSet<(usize, usize)>| {
+        if a != b {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    };
+    for a in 0..scale {
+        for _ in 0..6 {
+            add_edge(a, rng.gen_range(0..scale), &mut edges);
+        }
+    }
+    // plant a clique for part 2 to find
+    let mut clique = (0..scale).collect::<Vec<_>>();
+    clique.shuffle(rng);
+    clique.truncate(13);
+    for a in clique.iter() {
+        for b in clique.iter() {
+            add_edge(*a, *b, &mut edges);
+        }
+    }
+
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    edges.shuffle(rng);
+    let mut result = String::new();
+    for (a, b) in edges {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        result.push_str(&format!("{}-{}\n", names[a], names[b]));
+    }
+    Generated::new(result)
+}
+
+fn day24(rng: &mut StdRng, scale: usize) -> Generated {
+    // a ripple carry adder, with every internal wire given a random name
+    let mut used = HashSet::new();
+    let mut wire = |rng: &mut StdRng| loop {
+        let name = (0..3).map(|_| rng.gen_range('a'..='w')).collect::<String>();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut gates = Vec::new();
+    let mut gate = |rng: &mut StdRng, a: &str, op: &str, b: &str, out: &str| {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        gates.push(format!("{a} {op} {b} -> {out}"));
+    };
+    let mut carry = if scale == 1 { "z01".to_string() } else { wire(rng) };
+    gate(rng, "x00", "XOR", "y00", "z00");
+    gate(rng, "x00", "AND", "y00", &carry);
+    for i in 1..scale {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        let sum = wire(rng);
+        let both = wire(rng);
+        let carried = wire(rng);
+        let next_carry = if i + 1 == scale { format!("z{scale:02}") } else { wire(rng) };
+        gate(rng, &x, "XOR", &y, &sum);
+        gate(rng, &x, "AND", &y, &both);
+        gate(rng, &sum, "XOR", &carry, &z);
+        gate(rng, &sum, "AND", &carry, &carried);
+        gate(rng, &both, "OR", &carried, &next_carry);
+        carry = next_carry;
+    }
+    gates.shuffle(rng);
+
+    let mut result = String::new();
+    for prefix in ["x", "y"] {
+        for i in 0..scale {
+            result.push_str(&format!("{prefix}{i:02}: {}\n", rng.gen_range(0..2)));
+        }
+    }
+    result.push('\n');
+    result.push_str(&gates.join("\n"));
+    result.push('\n');
+    Generated::new(result)
+}
+
+fn day25(rng: &mut StdRng, scale: usize) -> Generated {
+    let mut schematics = Vec::with_capacity(scale);
+    for _ in 0..scale {
+        let is_lock = rng.gen_bool(0.5);
+        let heights = (0..5).map(|_| rng.gen_range(0..=5)).collect::<Vec<_>>();
+        let grid = (0..7)
+            .map(|y| {
+                heights
+                    .iter()
+                    .map(|h| {
+                        let filled = if is_lock { y <= *h } else { 6 - y <= *h };
+                        if filled {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+        schematics.push(grid_to_string(&grid));
+    }
+    Generated::new(schematics.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Part, SOLVERS};
+
+    #[test]
+    pub fn test_deterministic() {
+        for generator in GENERATORS.iter() {
+            let scale = generator.min_scale.max(8).min(generator.max_scale);
+            let a = generator.generate(scale, 42).unwrap();
+            let b = generator.generate(scale, 42).unwrap();
+            assert_eq!(a.input, b.input, "day {}", generator.day);
+        }
+    }
+
+    #[test]
+    pub fn test_out_of_range() {
+        assert!(find(17).unwrap().generate(22, 0).is_err());
+        assert!(find(5).unwrap().generate(9, 0).is_err());
+    }
+
+    #[test]
+    pub fn test_solvers_accept_generated_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-generate-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for solver in SOLVERS.iter().filter(|s| s.year == 2024) {
+            // brute force or unfinished, these don't terminate on arbitrary inputs
+            if (solver.day, solver.part) == (17, Part::B) || (solver.day, solver.part) == (24, Part::B) {
+                continue;
+            }
+            let generator = find(solver.day).unwrap();
+            let scale = generator.min_scale.max(11).min(generator.max_scale);
+            let generated = generator.generate(scale, 7).unwrap();
+            let path = dir.join(format!("{}.txt", solver.name()));
+            std::fs::write(&path, &generated.input).unwrap();
+            let result = solver.solve(path.to_str().unwrap(), &generated.params_for(solver));
+            assert!(result.is_ok(), "{}: {:?}", solver.name(), result);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+// a rectangular grid of cells stored row by row, which hands out views for looking at it differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            Err(format!("expected {width}x{height} = {} cells, got {}", width * height, cells.len()))?;
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn view(&self) -> View<'_, T> {
+        View {
+            grid: self,
+            origin: (0, 0),
+            step_x: (1, 0),
+            step_y: (0, 1),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<char> {
+    // one row per line, every line the same length, e.g. a puzzle input
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self> {
+        let width = lines.first().map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let before = cells.len();
+            cells.extend(line.as_ref().chars());
+            if cells.len() - before != width {
+                Err(format!("line {} is {} wide, expected {width}", y + 1, cells.len() - before))?;
+            }
+        }
+        Self::new(width, lines.len(), cells)
+    }
+}
+
+// a grid seen through some combination of rotations, flips and windows, without copying anything
+// every view maps its (x, y) onto the grid as origin + x * step_x + y * step_y, and each transform just
+// works out a new origin and steps, so they can be chained as much as you like
+#[derive(Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (i64, i64),
+    step_x: (i64, i64),
+    step_y: (i64, i64),
+    width: usize,
+    height: usize,
+}
+
+// derive would want T: Clone, but a view is only a reference and some numbers
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // where (x, y) in this view is in the underlying grid
+    pub fn to_grid(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let (x, y) = (x as i64, y as i64);
+        Some((
+            (self.origin.0 + x * self.step_x.0 + y * self.step_y.0) as usize,
+            (self.origin.1 + x * self.step_x.1 + y * self.step_y.1) as usize,
+        ))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        let (x, y) = self.to_grid(x, y)?;
+        self.grid.get(x, y)
+    }
+
+    // like get, but for coordinates that might have gone negative
+    pub fn get_signed(&self, x: i64, y: i64) -> Option<&'a T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.get(x as usize, y as usize)
+    }
+
+    // a new view whose (x, y) is this view's origin + x * step_x + y * step_y
+    fn map(&self, origin: (i64, i64), step_x: (i64, i64), step_y: (i64, i64), width: usize, height: usize) -> Self {
+        let apply = |(x, y): (i64, i64)| {
+            (
+                x * self.step_x.0 + y * self.step_y.0,
+                x * self.step_x.1 + y * self.step_y.1,
+            )
+        };
+        let (ox, oy) = apply(origin);
+        Self {
+            grid: self.grid,
+            origin: (self.origin.0 + ox, self.origin.1 + oy),
+            step_x: apply(step_x),
+            step_y: apply(step_y),
+            width,
+            height,
+        }
+    }
+
+    // a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_cw(&self) -> Self {
+        self.map((0, self.height as i64 - 1), (0, -1), (1, 0), self.height, self.width)
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.map((self.width as i64 - 1, self.height as i64 - 1), (-1, 0), (0, -1), self.width, self.height)
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.map((self.width as i64 - 1, 0), (0, 1), (-1, 0), self.height, self.width)
+    }
+
+    // turns clockwise, negative turns go anticlockwise
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => *self,
+            1 => self.rotate_cw(),
+            2 => self.rotate_180(),
+            _ => self.rotate_ccw(),
+        }
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Self {
+        self.map((0, 0), (0, 1), (1, 0), self.height, self.width)
+    }
+
+    // mirrored left to right
+    pub fn flip_horizontal(&self) -> Self {
+        self.map((self.width as i64 - 1, 0), (-1, 0), (0, 1), self.width, self.height)
+    }
+
+    // mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        self.map((0, self.height as i64 - 1), (1, 0), (0, -1), self.width, self.height)
+    }
+
+    // the 4 rotations, then the 4 rotations of the mirror image: every way of laying the grid down
+    pub fn orientations(&self) -> [Self; 8] {
+        let flipped = self.flip_horizontal();
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| if i < 4 { self.rotate(i) } else { flipped.rotate(i - 4) })
+    }
+
+    // the width x height rectangle with its top left at (x, y), None if it doesn't fit
+    pub fn window(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        if x + width > self.width || y + height > self.height {
+            return None;
+        }
+        Some(self.map((x as i64, y as i64), (1, 0), (0, 1), width, height))
+    }
+
+    // every k x k window that fits, row by row, with the position of its top left
+    pub fn windows(&self, k: usize) -> impl Iterator<Item = ((usize, usize), Self)> + 'a {
+        let view = *self;
+        let (columns, rows) = if k == 0 {
+            (0, 0)
+        } else {
+            ((self.width + 1).saturating_sub(k), (self.height + 1).saturating_sub(k))
+        };
+        (0..rows).flat_map(move |y| (0..columns).map(move |x| ((x, y), view.window(x, y, k, k).unwrap())))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..if y < self.height { self.width } else { 0 }).map(move |x| view.get(x, y).unwrap())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    // copies what the view shows into a grid of its own
+    pub fn to_owned_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.view().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(&["abc", "def"]).unwrap()
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert!(Grid::parse(&["abc", "de"]).unwrap_err().0.contains("line 2"));
+    }
+
+    #[test]
+    pub fn test_transforms() {
+        let grid = grid();
+        let view = grid.view();
+        assert_eq!(view.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(view.rotate_180().to_string(), "fed\ncba\n");
+        assert_eq!(view.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(view.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(view.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(view.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(view.rotate(-1).to_string(), view.rotate_ccw().to_string());
+        assert_eq!(view.rotate_cw().rotate_cw().to_string(), view.rotate_180().to_string());
+        assert_eq!(view.transpose().flip_horizontal().to_string(), view.rotate_cw().to_string());
+        assert_eq!(view.rotate_cw().to_grid(0, 0), Some((0, 1)));
+        assert_eq!(view.rotate_cw().get(2, 0), None);
+
+        let orientations = view.orientations().map(|v| v.to_string());
+        assert_eq!(orientations.iter().collect::<std::collections::HashSet<_>>().len(), 8);
+    }
+
+    #[test]
+    pub fn test_windows() {
+        let grid = Grid::parse(&["abcd", "efgh", "ijkl"]).unwrap();
+        let view = grid.view();
+        assert_eq!(view.window(1, 1, 3, 2).unwrap().to_string(), "fgh\njkl\n");
+        assert!(view.window(2, 1, 3, 2).is_none());
+        // windows of a rotated view are rotated too
+        assert_eq!(view.rotate_cw().window(0, 0, 2, 2).unwrap().to_string(), "ie\njf\n");
+
+        let windows = view.windows(2).collect::<Vec<_>>();
+        assert_eq!(windows.len(), 6);
+        assert_eq!(windows[0].0, (0, 0));
+        assert_eq!(windows[5].0, (2, 1));
+        assert_eq!(windows[5].1.to_string(), "gh\nkl\n");
+        assert_eq!(view.windows(4).count(), 0);
+        assert_eq!(view.windows(0).count(), 0);
+        assert_eq!(view.rotate_180().to_owned_grid().to_string(), "lkji\nhgfe\ndcba\n");
+    }
+}
@@ -0,0 +1,439 @@
+use std::{fmt::Display, path::Path};
+
+use crate::{
+    json::{self, Value},
+    runner::{self, Part},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<json::Error> for Error {
+    fn from(value: json::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Star {
+    pub day: u32,
+    pub part: Part,
+    // unix time it was earned
+    pub timestamp: u64,
+    // the order stars were earned in across the whole leaderboard, which breaks ties between equal timestamps
+    pub star_index: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    // as exported, which can differ from the recomputed one if a day was scored differently
+    pub local_score: u64,
+    // in the order they were earned
+    pub stars: Vec<Star>,
+}
+
+impl Member {
+    pub fn star(&self, day: u32, part: Part) -> Option<&Star> {
+        self.stars.iter().find(|s| s.day == day && s.part == part)
+    }
+
+    // seconds from part 1 to part 2 of a day, if both are done
+    pub fn part_gap(&self, day: u32) -> Option<u64> {
+        let (a, b) = (self.star(day, Part::A)?, self.star(day, Part::B)?);
+        Some(b.timestamp.saturating_sub(a.timestamp))
+    }
+}
+
+// a private leaderboard as exported from its JSON link on the site
+#[derive(Debug, Clone)]
+pub struct Leaderboard {
+    pub year: u32,
+    pub owner_id: u64,
+    // when day 1 unlocked, which newer exports include
+    pub day1_ts: Option<u64>,
+    pub members: Vec<Member>,
+}
+
+// some exports have numbers as strings
+fn number(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str()?.parse().ok())
+}
+
+fn field<'a>(value: &'a Value, key: &str, context: &str) -> Result<&'a Value> {
+    Ok(value.get(key).ok_or(format!("{context} has no {key}"))?)
+}
+
+fn number_field(value: &Value, key: &str, context: &str) -> Result<u64> {
+    Ok(number(field(value, key, context)?).ok_or(format!("{context} has a {key} that isn't a number"))?)
+}
+
+impl Leaderboard {
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let root = Value::parse(s)?;
+        let year = number_field(&root, "event", "leaderboard")? as u32;
+        let owner_id = number_field(&root, "owner_id", "leaderboard")?;
+        let day1_ts = root.get("day1_ts").and_then(number);
+        let mut members = Vec::new();
+        for (key, member) in field(&root, "members", "leaderboard")?
+            .as_object()
+            .ok_or("members isn't an object")?
+        {
+            let context = format!("member {key}");
+            let id = number_field(member, "id", &context)?;
+            let name = match member.get("name").and_then(|name| name.as_str()) {
+                Some(name) => name.to_string(),
+                // what the site shows for people who haven't set a name
+                None => format!("(anonymous user #{id})"),
+            };
+            let mut stars = Vec::new();
+            for (day, parts) in field(member, "completion_day_level", &context)?
+                .as_object()
+                .ok_or(format!("{context} has a completion_day_level that isn't an object"))?
+            {
+                let day = day.parse::<u32>().map_err(|_| format!("{context} has an invalid day: {day}"))?;
+                for (part, star) in parts.as_object().ok_or(format!("{context} day {day} isn't an object"))? {
+                    let context = format!("{context} day {day} part {part}");
+                    stars.push(Star {
+                        day,
+                        part: Part::try_from(part.as_str()).map_err(|e| e.0)?,
+                        timestamp: number_field(star, "get_star_ts", &context)?,
+                        star_index: star.get("star_index").and_then(number).unwrap_or(0),
+                    });
+                }
+            }
+            stars.sort_by_key(|s| (s.timestamp, s.star_index));
+            members.push(Member {
+                id,
+                name,
+                local_score: member.get("local_score").and_then(number).unwrap_or(0),
+                stars,
+            });
+        }
+        members.sort_by_key(|m| m.id);
+        Ok(Self {
+            year,
+            owner_id,
+            day1_ts,
+            members,
+        })
+    }
+
+    // unix time a day's puzzle unlocked, midnight in US eastern time, which is 05:00 UTC in December
+    pub fn release(&self, day: u32) -> u64 {
+        let day1 = self.day1_ts.unwrap_or_else(|| days_since_epoch(self.year, 12, 1) * 86400 + 5 * 3600);
+        day1 + (day as u64 - 1) * 86400
+    }
+
+    // seconds from a star's puzzle unlocking to it being earned
+    pub fn elapsed(&self, star: &Star) -> u64 {
+        star.timestamp.saturating_sub(self.release(star.day))
+    }
+
+    // the days anyone has a star for, in order
+    pub fn days(&self) -> Vec<u32> {
+        let mut result = self.members.iter().flat_map(|m| m.stars.iter().map(|s| s.day)).collect::<Vec<_>>();
+        result.sort();
+        result.dedup();
+        result
+    }
+
+    // local scores worked out from the stars, highest first: with n members, the first to get a star scores n,
+    // the second n - 1, and so on
+    pub fn local_scores(&self) -> Vec<(&Member, u64)> {
+        let mut scores = vec![0; self.members.len()];
+        for day in self.days() {
+            for part in [Part::A, Part::B] {
+                let mut finishers = (0..self.members.len())
+                    .filter_map(|i| Some((*self.members[i].star(day, part)?, i)))
+                    .collect::<Vec<_>>();
+                finishers.sort_by_key(|(s, _)| (s.timestamp, s.star_index));
+                for (rank, (_, i)) in finishers.iter().enumerate() {
+                    scores[*i] += (self.members.len() - rank) as u64;
+                }
+            }
+        }
+        let mut result = self.members.iter().zip(scores).collect::<Vec<_>>();
+        result.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.stars.len().cmp(&b.stars.len()).reverse()));
+        result
+    }
+
+    pub fn day_stats(&self, day: u32) -> DayStats {
+        let times = |part| {
+            let mut result = self
+                .members
+                .iter()
+                .filter_map(|m| Some(self.elapsed(m.star(day, part)?)))
+                .collect::<Vec<_>>();
+            result.sort();
+            result
+        };
+        let (part1, part2) = (times(Part::A), times(Part::B));
+        let mut gaps = self.members.iter().filter_map(|m| m.part_gap(day)).collect::<Vec<_>>();
+        gaps.sort();
+        DayStats {
+            day,
+            part1_stars: part1.len(),
+            part2_stars: part2.len(),
+            median_part1: median(&part1),
+            median_part2: median(&part2),
+            median_gap: median(&gaps),
+        }
+    }
+
+    // hardest first: fewest people finishing part 2, then the longest median time to finish it
+    pub fn days_by_difficulty(&self) -> Vec<DayStats> {
+        let mut result = self.days().into_iter().map(|day| self.day_stats(day)).collect::<Vec<_>>();
+        result.sort_by_key(|d| (d.part2_stars, std::cmp::Reverse(d.median_part2.unwrap_or(u64::MAX)), d.day));
+        result
+    }
+
+    pub fn report(&self) -> Report<'_> {
+        Report { leaderboard: self }
+    }
+}
+
+// days since 1970-01-01 for a date in the proleptic gregorian calendar
+fn days_since_epoch(year: u32, month: u32, day: u32) -> u64 {
+    // count years from March so the leap day comes last
+    let (year, month) = if month <= 2 { (year as i64 - 1, month + 9) } else { (year as i64, month - 3) };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month as i64 + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    (era * 146097 + day_of_era - 719468) as u64
+}
+
+// the lower middle one when there's an even number
+fn median(sorted: &[u64]) -> Option<u64> {
+    (!sorted.is_empty()).then(|| sorted[(sorted.len() - 1) / 2])
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: u32,
+    pub part1_stars: usize,
+    pub part2_stars: usize,
+    // seconds from the puzzle unlocking
+    pub median_part1: Option<u64>,
+    pub median_part2: Option<u64>,
+    // seconds from part 1 to part 2, among people who did both
+    pub median_gap: Option<u64>,
+}
+
+// how far this repo has got with a part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoStatus {
+    Solved,
+    Placeholder,
+    Unsolved,
+    // day 25 part 2 is a star for finishing everything else, there's no puzzle
+    Free,
+}
+
+impl RepoStatus {
+    pub fn of(year: u32, day: u32, part: Part) -> Self {
+        match runner::find_in_year(year, day, part) {
+            Some(solver) if solver.is_placeholder() => RepoStatus::Placeholder,
+            Some(_) => RepoStatus::Solved,
+            None if day == 25 && part == Part::B => RepoStatus::Free,
+            None => RepoStatus::Unsolved,
+        }
+    }
+}
+
+impl Display for RepoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepoStatus::Solved => write!(f, "solved"),
+            RepoStatus::Placeholder => write!(f, "placeholder"),
+            RepoStatus::Unsolved => write!(f, "unsolved"),
+            RepoStatus::Free => write!(f, "free"),
+        }
+    }
+}
+
+// e.g. 00:04:05, or 2d 03:04:05 once it's over a day
+pub fn format_duration(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60);
+    match days {
+        0 => time,
+        _ => format!("{days}d {time}"),
+    }
+}
+
+fn format_optional(seconds: Option<u64>) -> String {
+    seconds.map_or("-".to_string(), format_duration)
+}
+
+pub struct Report<'a> {
+    leaderboard: &'a Leaderboard,
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let leaderboard = self.leaderboard;
+        let width = leaderboard.members.iter().map(|m| m.name.len()).max().unwrap_or(0).max(6);
+
+        writeln!(f, "{} local scores", leaderboard.year)?;
+        writeln!(f, "{:>4}  {:width$}  {:>5}  {:>5}  {:>8}", "rank", "member", "stars", "score", "exported")?;
+        for (rank, (member, score)) in leaderboard.local_scores().into_iter().enumerate() {
+            let exported = if member.local_score == score { String::new() } else { member.local_score.to_string() };
+            writeln!(
+                f,
+                "{:>4}  {:width$}  {:>5}  {score:>5}  {exported:>8}",
+                rank + 1,
+                member.name,
+                member.stars.len()
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "days, hardest first (times are medians from the puzzle unlocking)")?;
+        writeln!(
+            f,
+            "{:>4}  {:>3}  {:>7}  {:>7}  {:>12}  {:>12}  {:>12}  {:>11}  {:>11}",
+            "rank", "day", "part 1", "part 2", "part 1 time", "part 2 time", "1 to 2", "repo part 1", "repo part 2"
+        )?;
+        for (rank, stats) in leaderboard.days_by_difficulty().into_iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:>3}  {:>7}  {:>7}  {:>12}  {:>12}  {:>12}  {:>11}  {:>11}",
+                rank + 1,
+                stats.day,
+                stats.part1_stars,
+                stats.part2_stars,
+                format_optional(stats.median_part1),
+                format_optional(stats.median_part2),
+                format_optional(stats.median_gap),
+                RepoStatus::of(leaderboard.year, stats.day, Part::A).to_string(),
+                RepoStatus::of(leaderboard.year, stats.day, Part::B).to_string(),
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "star timelines (part 1, part 2, and the time between)")?;
+        for member in leaderboard.members.iter().filter(|m| !m.stars.is_empty()) {
+            writeln!(f, "{}", member.name)?;
+            let mut days = member.stars.iter().map(|s| s.day).collect::<Vec<_>>();
+            days.sort();
+            days.dedup();
+            for day in days {
+                let time = |part| format_optional(member.star(day, part).map(|s| leaderboard.elapsed(s)));
+                let gap = member.part_gap(day).map_or(String::new(), |gap| format!("  (+{})", format_duration(gap)));
+                writeln!(f, "  day {day:>2}  {:>12}  {:>12}{gap}", time(Part::A), time(Part::B))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join("leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let leaderboard = fixture();
+        assert_eq!((leaderboard.year, leaderboard.owner_id), (2024, 101));
+        let names = leaderboard.members.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Alice", "Bob", "(anonymous user #103)"]);
+        // a timestamp given as a string
+        assert_eq!(leaderboard.elapsed(&leaderboard.members[2].stars[0]), 5000);
+        assert_eq!(leaderboard.days(), vec![1, 2, 17]);
+        assert!(Leaderboard::parse("{\"event\": \"2024\"}").unwrap_err().0.contains("owner_id"));
+    }
+
+    #[test]
+    pub fn test_release() {
+        let mut leaderboard = fixture();
+        let day3 = leaderboard.release(3);
+        leaderboard.day1_ts = None;
+        assert_eq!(leaderboard.release(3), day3);
+        assert_eq!(days_since_epoch(2000, 3, 1), 11017);
+        assert_eq!(days_since_epoch(1970, 1, 1), 0);
+    }
+
+    #[test]
+    pub fn test_local_scores() {
+        let leaderboard = fixture();
+        let scores = leaderboard
+            .local_scores()
+            .into_iter()
+            .map(|(m, score)| (m.id, score, m.local_score))
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![(101, 16, 16), (102, 10, 10), (103, 1, 1)]);
+    }
+
+    #[test]
+    pub fn test_days() {
+        let leaderboard = fixture();
+        assert_eq!(leaderboard.members[0].part_gap(17), Some(88000));
+        assert_eq!(leaderboard.members[1].part_gap(2), None);
+        assert_eq!(
+            leaderboard.day_stats(1),
+            DayStats {
+                day: 1,
+                part1_stars: 3,
+                part2_stars: 2,
+                median_part1: Some(400),
+                median_part2: Some(500),
+                median_gap: Some(100),
+            }
+        );
+        let order = leaderboard.days_by_difficulty().iter().map(|d| d.day).collect::<Vec<_>>();
+        assert_eq!(order, vec![17, 2, 1]);
+    }
+
+    #[test]
+    pub fn test_repo_status() {
+        assert_eq!(RepoStatus::of(2024, 1, Part::A), RepoStatus::Solved);
+        for name in ["day17b", "day20b", "day21a", "day22b", "day24b"] {
+            let solver = runner::find_by_name(name).unwrap();
+            assert_eq!(RepoStatus::of(solver.year, solver.day, solver.part), RepoStatus::Placeholder);
+        }
+        assert_eq!(RepoStatus::of(2024, 21, Part::B), RepoStatus::Unsolved);
+        assert_eq!(RepoStatus::of(2024, 25, Part::B), RepoStatus::Free);
+    }
+
+    #[test]
+    pub fn test_report() {
+        let report = fixture().report().to_string();
+        assert!(report.contains("   1  Alice                      6     16"));
+        assert!(report.contains("   1   17        2        1"));
+        assert!(report.contains("placeholder"));
+        assert!(report.contains("  day 17      00:33:20   1d 01:00:00  (+1d 00:26:40)"));
+        assert_eq!(format_duration(59), "00:00:59");
+        assert_eq!(format_duration(90061), "1d 01:01:01");
+    }
+}
@@ -1,48 +1,28 @@
-mod day01a;
-mod day01b;
-mod day02a;
-mod day02b;
-mod day03a;
-mod day03b;
-mod day04a;
-mod day04b;
-mod day05a;
-mod day05b;
-mod day06a;
-mod day06b;
-mod day07a;
-mod day07b;
-mod day08a;
-mod day08b;
-mod day09a;
-mod day09b;
-mod day10a;
-mod day10b;
-mod day11a;
-mod day11b;
-mod day12a;
-mod day12b;
-mod day13a;
-mod day13b;
-mod day14a;
-mod day14b;
-mod day15a;
-mod day15b;
-mod day16a;
-mod day16b;
-mod day17a;
-mod day17b;
-mod day18a;
-mod day18b;
-mod day19a;
-mod day19b;
-mod day20a;
-mod day20b;
-mod day21a;
-mod day22a;
-mod day22b;
-mod day23a;
-mod day23b;
-mod day24a;
-mod day24b;
-mod day25a;
+mod y2024;
+
+pub mod alloc_profile;
+pub mod answer;
+pub mod bench;
+pub mod bit_grid;
+pub mod checked;
+pub mod cross_check;
+pub mod fast_map;
+pub mod fetch;
+pub mod generate;
+pub mod grid_view;
+pub mod json;
+pub mod leaderboard;
+#[cfg(test)]
+mod mock_server;
+pub mod pattern;
+pub mod progress;
+pub mod runner;
+pub mod samples;
+pub mod scaffold;
+#[cfg(test)]
+mod snapshot;
+pub mod server;
+pub mod simulation;
+pub mod submit;
+pub mod table;
+pub mod test_vectors;
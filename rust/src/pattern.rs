@@ -0,0 +1,332 @@
+use std::fmt::Display;
+
+use crate::grid_view::{self, Grid, View};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<grid_view::Error> for Error {
+    fn from(value: grid_view::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell<T> {
+    Is(T),
+    // matches anything in the grid, but still has to be on it: a placement only counts when the whole template,
+    // wildcards and all, fits inside the view
+    Any,
+}
+
+// which ways round a pattern can be laid over the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    // only the way it's written
+    Fixed,
+    // the 4 quarter turns
+    Rotations,
+    // the 4 quarter turns of it and of its mirror image
+    All,
+}
+
+// how a template was laid down to match, in the terms of grid_view::View::orientations: flipped left to right
+// first if at all, then turned clockwise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Orientation {
+    pub flipped: bool,
+    pub quarter_turns: u8,
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let turned = ["upright", "cw", "180", "ccw"][self.quarter_turns as usize % 4];
+        match self.flipped {
+            true => write!(f, "flipped {turned}"),
+            false => write!(f, "{turned}"),
+        }
+    }
+}
+
+// one or more templates that all count as the same thing, like a word written across or diagonally
+#[derive(Debug, Clone)]
+pub struct Pattern<T> {
+    pub name: String,
+    pub shapes: Vec<Grid<Cell<T>>>,
+    pub symmetry: Symmetry,
+}
+
+impl<T> Pattern<T> {
+    pub fn new(name: &str, shapes: Vec<Grid<Cell<T>>>, symmetry: Symmetry) -> Self {
+        Self {
+            name: name.to_string(),
+            shapes,
+            symmetry,
+        }
+    }
+}
+
+impl Pattern<char> {
+    // rows of a template, where wildcard matches anything and every other character only itself
+    pub fn parse<S: AsRef<str>>(name: &str, rows: &[S], wildcard: char, symmetry: Symmetry) -> Result<Self> {
+        let shape = Grid::parse(rows)?;
+        if shape.width() == 0 || shape.height() == 0 {
+            Err(format!("pattern {name} is empty"))?;
+        }
+        Ok(Self::new(name, vec![cells(&shape, wildcard)], symmetry))
+    }
+
+    // a word read in a straight line in any of the eight directions, which is the word across and the word down
+    // the diagonal, in all their rotations
+    pub fn word(word: &str) -> Result<Self> {
+        let letters = word.chars().collect::<Vec<_>>();
+        if letters.is_empty() {
+            Err("can't search for an empty word")?;
+        }
+        let n = letters.len();
+        let across = Grid::new(n, 1, letters.iter().map(|c| Cell::Is(*c)).collect())?;
+        let diagonal = (0..n * n)
+            .map(|i| match i % n == i / n {
+                true => Cell::Is(letters[i % n]),
+                false => Cell::Any,
+            })
+            .collect();
+        let diagonal = Grid::new(n, n, diagonal)?;
+        Ok(Self::new(word, vec![across, diagonal], Symmetry::Rotations))
+    }
+}
+
+fn cells(shape: &Grid<char>, wildcard: char) -> Grid<Cell<char>> {
+    let cells = shape
+        .view()
+        .rows()
+        .flatten()
+        .map(|c| if *c == wildcard { Cell::Any } else { Cell::Is(*c) })
+        .collect();
+    Grid::new(shape.width(), shape.height(), cells).unwrap()
+}
+
+// a shape laid down one particular way
+#[derive(Debug, Clone)]
+struct Placed<T> {
+    pattern: usize,
+    shape: usize,
+    orientation: Orientation,
+    width: usize,
+    height: usize,
+    // (x, y) in the placed shape, in the order the cells come in the shape as written, so a word's letters are
+    // in order
+    literals: Vec<(usize, usize, T)>,
+}
+
+impl<T: PartialEq> Placed<T> {
+    fn same_cells(&self, other: &Self) -> bool {
+        (self.width, self.height) == (other.width, other.height)
+            && self.literals.len() == other.literals.len()
+            && self.literals.iter().all(|cell| other.literals.contains(cell))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    // index into the patterns in the order they were added
+    pub pattern: usize,
+    pub shape: usize,
+    pub orientation: Orientation,
+    // the top left of the placed shape in the view searched
+    pub position: (usize, usize),
+    // where each of the shape's literal cells landed, in the order they're written in the shape
+    pub cells: Vec<(usize, usize)>,
+}
+
+// any number of patterns, searched for together in one pass over a grid
+// a placement that covers exactly the same cells as one already there, like a palindrome backwards or an
+// X-MAS flipped, is only tried once, so nothing is found twice
+#[derive(Debug, Clone)]
+pub struct Matcher<T> {
+    patterns: Vec<Pattern<T>>,
+    placed: Vec<Placed<T>>,
+}
+
+impl<T> Default for Matcher<T> {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            placed: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Matcher<T> {
+    pub fn new(patterns: Vec<Pattern<T>>) -> Self {
+        let mut result = Self::default();
+        for pattern in patterns {
+            result.add(pattern);
+        }
+        result
+    }
+
+    // gives the pattern's index, which its matches refer to it by
+    pub fn add(&mut self, pattern: Pattern<T>) -> usize {
+        let index = self.patterns.len();
+        let first = self.placed.len();
+        for (s, shape) in pattern.shapes.iter().enumerate() {
+            let orientations = match pattern.symmetry {
+                Symmetry::Fixed => 1,
+                Symmetry::Rotations => 4,
+                Symmetry::All => 8,
+            };
+            for (i, view) in shape.view().orientations().into_iter().enumerate().take(orientations) {
+                let placed = place(index, s, i, &view);
+                if !self.placed[first..].iter().any(|p| p.same_cells(&placed)) {
+                    self.placed.push(placed);
+                }
+            }
+        }
+        self.patterns.push(pattern);
+        index
+    }
+
+    pub fn patterns(&self) -> &[Pattern<T>] {
+        &self.patterns
+    }
+
+    // the ways round each pattern can be placed, after leaving out the ones that cover the same cells
+    pub fn placements(&self, pattern: usize) -> usize {
+        self.placed.iter().filter(|p| p.pattern == pattern).count()
+    }
+
+    // every match of every pattern, row by row, and in the order the patterns were added at each position
+    pub fn find_all(&self, view: &View<T>) -> Vec<Match> {
+        let mut result = Vec::new();
+        for y in 0..view.height() {
+            for x in 0..view.width() {
+                for placed in self.placed.iter() {
+                    if x + placed.width > view.width() || y + placed.height > view.height() {
+                        continue;
+                    }
+                    let matches = placed
+                        .literals
+                        .iter()
+                        .all(|(dx, dy, value)| view.get(x + dx, y + dy) == Some(value));
+                    if matches {
+                        result.push(Match {
+                            pattern: placed.pattern,
+                            shape: placed.shape,
+                            orientation: placed.orientation,
+                            position: (x, y),
+                            cells: placed.literals.iter().map(|(dx, dy, _)| (x + dx, y + dy)).collect(),
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    // how many matches each pattern has
+    pub fn count(&self, view: &View<T>) -> Vec<usize> {
+        let mut result = vec![0; self.patterns.len()];
+        for m in self.find_all(view) {
+            result[m.pattern] += 1;
+        }
+        result
+    }
+}
+
+fn place<T: Clone>(pattern: usize, shape: usize, i: usize, view: &View<Cell<T>>) -> Placed<T> {
+    let mut literals = Vec::new();
+    for y in 0..view.height() {
+        for x in 0..view.width() {
+            if let Cell::Is(value) = view.get(x, y).unwrap() {
+                let (sx, sy) = view.to_grid(x, y).unwrap();
+                literals.push(((sy, sx), (x, y, value.clone())));
+            }
+        }
+    }
+    literals.sort_by_key(|(written, _)| *written);
+    Placed {
+        pattern,
+        shape,
+        orientation: Orientation {
+            flipped: i >= 4,
+            quarter_turns: (i % 4) as u8,
+        },
+        width: view.width(),
+        height: view.height(),
+        literals: literals.into_iter().map(|(_, cell)| cell).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_word() {
+        let grid = Grid::parse(&["XMAS.", "M..S.", "A.A..", "SM...", "X...."]).unwrap();
+        let matcher = Matcher::new(vec![Pattern::word("XMAS").unwrap()]);
+        assert_eq!(matcher.placements(0), 8);
+        let matches = matcher.find_all(&grid.view());
+        let found = matches.iter().map(|m| (m.shape, m.orientation.to_string(), m.cells[0])).collect::<Vec<_>>();
+        // across, down, and up from the bottom corner diagonally
+        assert_eq!(
+            found,
+            vec![(0, "upright".to_string(), (0, 0)), (0, "cw".to_string(), (0, 0)), (1, "ccw".to_string(), (0, 4))]
+        );
+        assert_eq!(matches[2].cells, vec![(0, 4), (1, 3), (2, 2), (3, 1)]);
+        assert_eq!(matches[2].position, (0, 1));
+    }
+
+    #[test]
+    pub fn test_symmetries() {
+        // a palindrome reads the same both ways, so only across and down are different
+        let matcher = Matcher::new(vec![Pattern::parse("aba", &["aba"], '.', Symmetry::All).unwrap()]);
+        assert_eq!(matcher.placements(0), 2);
+        let grid = Grid::parse(&["a..a", "cddc"]).unwrap();
+        let l = |symmetry| Pattern::parse("L", &["a.", "cd"], '.', symmetry).unwrap();
+        for (symmetry, placements, found) in [(Symmetry::Fixed, 1, 1), (Symmetry::Rotations, 4, 1), (Symmetry::All, 8, 2)] {
+            let matcher = Matcher::new(vec![l(symmetry)]);
+            assert_eq!(matcher.placements(0), placements);
+            assert_eq!(matcher.count(&grid.view()), vec![found]);
+        }
+        let matcher = Matcher::new(vec![l(Symmetry::All)]);
+        let orientations = matcher.find_all(&grid.view()).iter().map(|m| m.orientation).collect::<Vec<_>>();
+        assert_eq!(
+            orientations,
+            vec![
+                Orientation { flipped: false, quarter_turns: 0 },
+                Orientation { flipped: true, quarter_turns: 0 }
+            ]
+        );
+        assert!(Pattern::parse("empty", &[""], '.', Symmetry::Fixed).is_err());
+        assert!(Pattern::word("").is_err());
+    }
+
+    #[test]
+    pub fn test_several_patterns() {
+        let grid = Grid::parse(&["M.S", ".A.", "M.S"]).unwrap();
+        let matcher = Matcher::new(vec![
+            Pattern::word("MAS").unwrap(),
+            Pattern::parse("X-MAS", &["M.S", ".A.", "M.S"], '.', Symmetry::All).unwrap(),
+        ]);
+        assert_eq!(matcher.placements(1), 4);
+        assert_eq!(matcher.count(&grid.view()), vec![2, 1]);
+        // a rotated view is searched as it's seen
+        assert_eq!(matcher.find_all(&grid.view().rotate_cw())[2].orientation.to_string(), "cw");
+    }
+}
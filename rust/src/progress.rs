@@ -0,0 +1,236 @@
+use std::{
+    io::{IsTerminal, Write},
+    sync::{
+        atomic::{AtomicU64, AtomicU8, Ordering},
+        Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+// how progress reports get shown, nothing is shown unless something like aoc run turns it on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    // one line on stderr that keeps getting overwritten
+    Terminal,
+    // a plain line every so often, for when stderr is a file or a pipe
+    Log,
+}
+
+impl Mode {
+    // terminal if stderr is a terminal, log otherwise
+    pub fn detect() -> Self {
+        if std::io::stderr().is_terminal() {
+            Mode::Terminal
+        } else {
+            Mode::Log
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        match self {
+            Mode::Off => Duration::MAX,
+            Mode::Terminal => Duration::from_millis(100),
+            Mode::Log => Duration::from_secs(5),
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Mode::Terminal,
+            2 => Mode::Log,
+            _ => Mode::Off,
+        }
+    }
+}
+
+impl TryFrom<&str> for Mode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "auto" => Ok(Mode::detect()),
+            "terminal" | "tty" => Ok(Mode::Terminal),
+            "log" => Ok(Mode::Log),
+            "off" => Ok(Mode::Off),
+            _ => Err(format!("invalid progress mode: {value}, expected auto, terminal, log, or off")),
+        }
+    }
+}
+
+// lets a caller in a hot loop through at most once per interval, without taking a lock
+pub struct Throttle {
+    start: Instant,
+    // in nanoseconds
+    interval: AtomicU64,
+    // nanoseconds after start when the next caller gets through
+    next: AtomicU64,
+}
+
+impl Throttle {
+    pub fn new(start: Instant, interval: Duration) -> Self {
+        Self {
+            start,
+            interval: AtomicU64::new(nanos(interval)),
+            next: AtomicU64::new(0),
+        }
+    }
+
+    // lets the next caller from now on through straight away, and then once per the new interval
+    pub fn restart(&self, now: Instant, interval: Duration) {
+        self.interval.store(nanos(interval), Ordering::Relaxed);
+        self.next.store(nanos(now.duration_since(self.start)), Ordering::Relaxed);
+    }
+
+    pub fn ready(&self, now: Instant) -> bool {
+        let now = nanos(now.duration_since(self.start));
+        let next = self.next.load(Ordering::Relaxed);
+        let interval = self.interval.load(Ordering::Relaxed);
+        now >= next
+            && self
+                .next
+                .compare_exchange(next, now.saturating_add(interval), Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn si(x: f64) -> String {
+    match x {
+        x if x >= 1e9 => format!("{:.1}G", x / 1e9),
+        x if x >= 1e6 => format!("{:.1}M", x / 1e6),
+        x if x >= 1e3 => format!("{:.1}k", x / 1e3),
+        x => format!("{x:.0}"),
+    }
+}
+
+// e.g. "day06b 1200/5000 24.0% 3.1k/s trying obstacles" or "day17b 1.2M 400.0k/s a = 1200000"
+pub fn format_line(label: &str, current: u64, total: Option<u64>, status: &str, elapsed: Duration) -> String {
+    let rate = current as f64 / elapsed.as_secs_f64().max(1e-9);
+    let mut result = match total {
+        Some(total) => format!(
+            "{label} {current}/{total} {:.1}% {}/s",
+            current as f64 * 100.0 / (total.max(1)) as f64,
+            si(rate)
+        ),
+        None => format!("{label} {} {}/s", si(current as f64), si(rate)),
+    };
+    if !status.is_empty() {
+        result.push(' ');
+        result.push_str(status);
+    }
+    result
+}
+
+struct Task {
+    label: String,
+    start: Instant,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(0);
+static TASK: Mutex<Option<Task>> = Mutex::new(None);
+
+// outside TASK, so reports that aren't due yet never take the lock
+fn throttle() -> &'static Throttle {
+    static THROTTLE: OnceLock<Throttle> = OnceLock::new();
+    THROTTLE.get_or_init(|| Throttle::new(Instant::now(), Duration::MAX))
+}
+
+// starts showing progress for a solver run, reports before this or after finish() are dropped
+pub fn start(label: &str, mode: Mode) {
+    let now = Instant::now();
+    *TASK.lock().unwrap() = Some(Task {
+        label: label.to_string(),
+        start: now,
+    });
+    throttle().restart(now, mode.interval());
+    MODE.store(mode as u8, Ordering::SeqCst);
+}
+
+pub fn finish() {
+    let mode = Mode::from_u8(MODE.swap(Mode::Off as u8, Ordering::SeqCst));
+    if mode == Mode::Terminal {
+        eprint!("\r\x1b[2K");
+        let _ = std::io::stderr().flush();
+    }
+    *TASK.lock().unwrap() = None;
+}
+
+// for solvers to call as often as they like, total is None when there's no telling how far there is to go
+pub fn report(current: u64, total: Option<u64>, status: &str) {
+    report_with(current, total, || status.to_string());
+}
+
+// like report, but only builds the status when it's actually going to be shown
+pub fn report_with(current: u64, total: Option<u64>, status: impl FnOnce() -> String) {
+    let mode = Mode::from_u8(MODE.load(Ordering::Relaxed));
+    if mode == Mode::Off {
+        return;
+    }
+    let now = Instant::now();
+    if !throttle().ready(now) {
+        return;
+    }
+    // only once per interval, and the line is written holding the lock so finish() can't clear it first
+    let task = TASK.lock().unwrap();
+    let Some(task) = task.as_ref() else {
+        return;
+    };
+    let elapsed = now.saturating_duration_since(task.start);
+    let line = format_line(&task.label, current, total, &status(), elapsed);
+    match mode {
+        Mode::Terminal => {
+            eprint!("\r{line}\x1b[K");
+            let _ = std::io::stderr().flush();
+        }
+        Mode::Log => eprintln!("[{:>8.1}s] {line}", elapsed.as_secs_f64()),
+        Mode::Off => {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_format_line() {
+        assert_eq!(
+            format_line("day06b", 1200, Some(5000), "trying obstacles", Duration::from_millis(400)),
+            "day06b 1200/5000 24.0% 3.0k/s trying obstacles"
+        );
+        assert_eq!(
+            format_line("day17b", 1_200_000, None, "", Duration::from_secs(3)),
+            "day17b 1.2M 400.0k/s"
+        );
+        assert_eq!(format_line("x", 0, Some(0), "", Duration::ZERO), "x 0/0 0.0% 0/s");
+    }
+
+    #[test]
+    pub fn test_throttle() {
+        let start = Instant::now();
+        let throttle = Throttle::new(start, Duration::from_millis(100));
+        assert!(throttle.ready(start));
+        assert!(!throttle.ready(start));
+        assert!(!throttle.ready(start + Duration::from_millis(99)));
+        assert!(throttle.ready(start + Duration::from_millis(150)));
+        assert!(!throttle.ready(start + Duration::from_millis(200)));
+        assert!(throttle.ready(start + Duration::from_millis(250)));
+        throttle.restart(start + Duration::from_millis(260), Duration::from_secs(5));
+        assert!(throttle.ready(start + Duration::from_millis(260)));
+        assert!(!throttle.ready(start + Duration::from_secs(5)));
+        assert!(throttle.ready(start + Duration::from_millis(5260)));
+    }
+
+    #[test]
+    pub fn test_mode() {
+        assert_eq!(Mode::try_from("log"), Ok(Mode::Log));
+        assert_eq!(Mode::try_from("tty"), Ok(Mode::Terminal));
+        assert!(Mode::try_from("loud").is_err());
+        for mode in [Mode::Off, Mode::Terminal, Mode::Log] {
+            assert_eq!(Mode::from_u8(mode as u8), mode);
+        }
+    }
+}
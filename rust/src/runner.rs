@@ -0,0 +1,540 @@
+use std::{
+    fmt::{Debug, Display},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{answer::Answer, *};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("invalid part: {value}"))?,
+        }
+    }
+}
+
+// an extra numeric argument a solver takes besides the input path, e.g. the grid size for day 14
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+}
+
+type Solve = fn(&str, &[u64]) -> std::result::Result<Answer, String>;
+
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub params: &'static [Param],
+    solve: Solve,
+}
+
+impl Solver {
+    pub fn name(&self) -> String {
+        format!("day{:02}{}", self.day, self.part)
+    }
+
+    // the real puzzle input, relative to the year's puzzle inputs directory
+    pub fn input(&self) -> String {
+        format!("day{:02}.txt", self.day)
+    }
+
+    // resolve the full parameter list, using the default for anything not overridden
+    pub fn resolve_params(&self, overrides: &[(String, u64)]) -> Result<Vec<u64>> {
+        resolve_params(&self.name(), self.params, overrides)
+    }
+
+    // path is either relative to the year's puzzle inputs directory or absolute
+    pub fn solve(&self, path: &str, overrides: &[(String, u64)]) -> Result<Answer> {
+        let params = self.resolve_params(overrides)?;
+        Ok((self.solve)(path, &params)?)
+    }
+
+    pub fn is_placeholder(&self) -> bool {
+        PLACEHOLDERS.contains(&(self.year, self.day, self.part))
+    }
+
+    // every way there is of solving this part, starting with the solver itself as "default"
+    pub fn strategies(&self) -> Vec<Strategy> {
+        let mut result = vec![Strategy {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            name: "default",
+            solve: self.solve,
+        }];
+        result.extend(
+            STRATEGIES
+                .iter()
+                .filter(|s| (s.year, s.day, s.part) == (self.year, self.day, self.part))
+                .copied(),
+        );
+        result
+    }
+}
+
+impl Debug for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Solver")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("part", &self.part)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+fn resolve_params(owner: &str, params: &[Param], overrides: &[(String, u64)]) -> Result<Vec<u64>> {
+    if let Some((name, _)) = overrides
+        .iter()
+        .find(|(name, _)| !params.iter().any(|p| p.name == name))
+    {
+        Err(format!("{owner} has no parameter named {name}"))?;
+    }
+    Ok(params
+        .iter()
+        .map(|p| {
+            overrides
+                .iter()
+                .rev()
+                .find(|(name, _)| name == p.name)
+                .map(|(_, value)| *value)
+                .unwrap_or(p.default)
+        })
+        .collect())
+}
+
+// a solver that returns a string has it parsed, so an empty one fails rather than counting as an answer
+fn answer<T, E>(result: std::result::Result<T, E>) -> std::result::Result<Answer, String>
+where
+    T: TryInto<Answer>,
+    T::Error: Debug,
+    E: Debug,
+{
+    result.map_err(|e| format!("{e:?}"))?.try_into().map_err(|e| format!("{e:?}"))
+}
+
+const DAY02B_PARAMS: &[Param] = &[
+    Param {
+        name: "removals",
+        default: 1,
+    },
+    Param {
+        name: "min_step",
+        default: 1,
+    },
+    Param {
+        name: "max_step",
+        default: 3,
+    },
+    // 0 for either, 1 for increasing, 2 for decreasing
+    Param {
+        name: "direction",
+        default: 0,
+    },
+];
+
+const DAY14_PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: 101,
+    },
+    Param {
+        name: "height",
+        default: 103,
+    },
+];
+
+const DAY18A_PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: 71,
+    },
+    Param {
+        name: "height",
+        default: 71,
+    },
+    Param {
+        name: "count",
+        default: 1024,
+    },
+];
+
+const DAY18B_PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: 71,
+    },
+    Param {
+        name: "height",
+        default: 71,
+    },
+];
+
+const DAY20_PARAMS: &[Param] = &[Param {
+    name: "at_least_time_saved",
+    default: 100,
+}];
+
+#[rustfmt::skip]
+pub static SOLVERS: &[Solver] = &[
+    Solver { year: 2024, day: 1, part: Part::A, params: &[], solve: |path, _| answer(y2024::day01a::do_it(path)) },
+    Solver { year: 2024, day: 1, part: Part::B, params: &[], solve: |path, _| answer(y2024::day01b::do_it(path)) },
+    Solver { year: 2024, day: 2, part: Part::A, params: &[], solve: |path, _| answer(y2024::day02a::do_it(path)) },
+    Solver { year: 2024, day: 2, part: Part::B, params: DAY02B_PARAMS, solve: |path, p| answer(y2024::day02b::do_it(path, p)) },
+    Solver { year: 2024, day: 3, part: Part::A, params: &[], solve: |path, _| answer(y2024::day03a::do_it(path)) },
+    Solver { year: 2024, day: 3, part: Part::B, params: &[], solve: |path, _| answer(y2024::day03b::do_it(path)) },
+    Solver { year: 2024, day: 4, part: Part::A, params: &[], solve: |path, _| answer(y2024::day04a::do_it(path)) },
+    Solver { year: 2024, day: 4, part: Part::B, params: &[], solve: |path, _| answer(y2024::day04b::do_it(path)) },
+    Solver { year: 2024, day: 5, part: Part::A, params: &[], solve: |path, _| answer(y2024::day05a::do_it(path)) },
+    Solver { year: 2024, day: 5, part: Part::B, params: &[], solve: |path, _| answer(y2024::day05b::do_it(path)) },
+    Solver { year: 2024, day: 6, part: Part::A, params: &[], solve: |path, _| answer(y2024::day06a::do_it(path)) },
+    Solver { year: 2024, day: 6, part: Part::B, params: &[], solve: |path, _| answer(y2024::day06b::do_it(path)) },
+    Solver { year: 2024, day: 7, part: Part::A, params: &[], solve: |path, _| answer(y2024::day07a::do_it(path)) },
+    Solver { year: 2024, day: 7, part: Part::B, params: &[], solve: |path, _| answer(y2024::day07b::do_it(path)) },
+    Solver { year: 2024, day: 8, part: Part::A, params: &[], solve: |path, _| answer(y2024::day08a::do_it(path)) },
+    Solver { year: 2024, day: 8, part: Part::B, params: &[], solve: |path, _| answer(y2024::day08b::do_it(path)) },
+    Solver { year: 2024, day: 9, part: Part::A, params: &[], solve: |path, _| answer(y2024::day09a::do_it(path)) },
+    Solver { year: 2024, day: 9, part: Part::B, params: &[], solve: |path, _| answer(y2024::day09b::do_it(path)) },
+    Solver { year: 2024, day: 10, part: Part::A, params: &[], solve: |path, _| answer(y2024::day10a::do_it(path)) },
+    Solver { year: 2024, day: 10, part: Part::B, params: &[], solve: |path, _| answer(y2024::day10b::do_it(path)) },
+    Solver { year: 2024, day: 11, part: Part::A, params: &[], solve: |path, _| answer(y2024::day11a::do_it(path)) },
+    Solver { year: 2024, day: 11, part: Part::B, params: &[], solve: |path, _| answer(y2024::day11b::do_it(path)) },
+    Solver { year: 2024, day: 12, part: Part::A, params: &[], solve: |path, _| answer(y2024::day12a::do_it(path)) },
+    Solver { year: 2024, day: 12, part: Part::B, params: &[], solve: |path, _| answer(y2024::day12b::do_it(path)) },
+    Solver { year: 2024, day: 13, part: Part::A, params: &[], solve: |path, _| answer(y2024::day13a::do_it(path)) },
+    Solver { year: 2024, day: 13, part: Part::B, params: &[], solve: |path, _| answer(y2024::day13b::do_it(path)) },
+    Solver { year: 2024, day: 14, part: Part::A, params: DAY14_PARAMS, solve: |path, p| answer(y2024::day14a::do_it(path, p[0] as usize, p[1] as usize)) },
+    Solver { year: 2024, day: 14, part: Part::B, params: DAY14_PARAMS, solve: |path, p| answer(y2024::day14b::do_it(path, p[0] as usize, p[1] as usize)) },
+    Solver { year: 2024, day: 15, part: Part::A, params: &[], solve: |path, _| answer(y2024::day15a::do_it(path)) },
+    Solver { year: 2024, day: 15, part: Part::B, params: &[], solve: |path, _| answer(y2024::day15b::do_it(path)) },
+    Solver { year: 2024, day: 16, part: Part::A, params: &[], solve: |path, _| answer(y2024::day16a::do_it(path)) },
+    Solver { year: 2024, day: 16, part: Part::B, params: &[], solve: |path, _| answer(y2024::day16b::do_it(path)) },
+    Solver { year: 2024, day: 17, part: Part::A, params: &[], solve: |path, _| answer(y2024::day17a::do_it(path)) },
+    Solver { year: 2024, day: 17, part: Part::B, params: &[], solve: |path, _| answer(y2024::day17b::do_it(path)) },
+    Solver { year: 2024, day: 18, part: Part::A, params: DAY18A_PARAMS, solve: |path, p| answer(y2024::day18a::do_it(path, p[0] as usize, p[1] as usize, p[2] as usize)) },
+    Solver { year: 2024, day: 18, part: Part::B, params: DAY18B_PARAMS, solve: |path, p| answer(y2024::day18b::do_it(path, p[0] as usize, p[1] as usize)) },
+    Solver { year: 2024, day: 19, part: Part::A, params: &[], solve: |path, _| answer(y2024::day19a::do_it(path)) },
+    Solver { year: 2024, day: 19, part: Part::B, params: &[], solve: |path, _| answer(y2024::day19b::do_it(path)) },
+    Solver { year: 2024, day: 20, part: Part::A, params: DAY20_PARAMS, solve: |path, p| answer(y2024::day20a::do_it(path, p[0])) },
+    Solver { year: 2024, day: 20, part: Part::B, params: DAY20_PARAMS, solve: |path, p| answer(y2024::day20b::do_it(path, p[0])) },
+    Solver { year: 2024, day: 21, part: Part::A, params: &[], solve: |path, _| answer(y2024::day21a::do_it(path)) },
+    Solver { year: 2024, day: 22, part: Part::A, params: &[], solve: |path, _| answer(y2024::day22a::do_it(path)) },
+    Solver { year: 2024, day: 22, part: Part::B, params: &[], solve: |path, _| answer(y2024::day22b::do_it(path)) },
+    Solver { year: 2024, day: 23, part: Part::A, params: &[], solve: |path, _| answer(y2024::day23a::do_it(path)) },
+    Solver { year: 2024, day: 23, part: Part::B, params: &[], solve: |path, _| answer(y2024::day23b::do_it(path)) },
+    Solver { year: 2024, day: 24, part: Part::A, params: &[], solve: |path, _| answer(y2024::day24a::do_it(path)) },
+    Solver { year: 2024, day: 24, part: Part::B, params: &[], solve: |path, _| answer(y2024::day24b::do_it(path, |x, y| x + y)) },
+    Solver { year: 2024, day: 25, part: Part::A, params: &[], solve: |path, _| answer(y2024::day25a::do_it(path, |x, y| x + y)) },
+];
+
+// another way of solving a part that SOLVERS already has, kept so the two can be checked against each other
+// takes the same parameters as the part's solver
+#[derive(Clone, Copy)]
+pub struct Strategy {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub name: &'static str,
+    solve: Solve,
+}
+
+impl Strategy {
+    pub fn solve_with_timeout(&self, path: &str, params: &[u64], timeout: Duration) -> Outcome {
+        call_with_timeout(self.solve, path, params.to_vec(), timeout)
+    }
+}
+
+#[rustfmt::skip]
+pub static STRATEGIES: &[Strategy] = &[
+    Strategy { year: 2024, day: 2, part: Part::B, name: "rebuilding", solve: |path, p| answer(y2024::day02b::do_it_by_rebuilding(path, p)) },
+    Strategy { year: 2024, day: 3, part: Part::A, name: "regex", solve: |path, _| answer(y2024::day03a::do_it_with_regex(path)) },
+    Strategy { year: 2024, day: 3, part: Part::B, name: "regex", solve: |path, _| answer(y2024::day03b::do_it_with_regex(path)) },
+    Strategy { year: 2024, day: 4, part: Part::A, name: "rotated grid", solve: |path, _| answer(y2024::day04a::do_it_with_rotations(path)) },
+    Strategy { year: 2024, day: 4, part: Part::B, name: "rotated grid", solve: |path, _| answer(y2024::day04b::do_it_with_rotations(path)) },
+    Strategy { year: 2024, day: 5, part: Part::A, name: "violations", solve: |path, _| answer(y2024::day05a::do_it_by_violations(path)) },
+    Strategy { year: 2024, day: 5, part: Part::B, name: "backtracking", solve: |path, _| answer(y2024::day05b::do_it_by_backtracking(path)) },
+    Strategy { year: 2024, day: 9, part: Part::A, name: "file moves", solve: |path, _| answer(y2024::day09a::do_it_by_file_moves(path)) },
+    Strategy { year: 2024, day: 13, part: Part::B, name: "exact", solve: |path, _| answer(y2024::day13b::do_it_exact(path)) },
+    Strategy { year: 2024, day: 18, part: Part::B, name: "linear scan", solve: |path, p| answer(y2024::day18b::do_it_linear(path, p[0] as usize, p[1] as usize)) },
+];
+
+type Analyse = fn(&str, &[u64]) -> std::result::Result<String, String>;
+
+// a report on a day's input that goes further than the answer, for working out why some input gives an
+// answer nobody expected
+pub struct Analysis {
+    pub year: u32,
+    pub day: u32,
+    pub params: &'static [Param],
+    analyse: Analyse,
+}
+
+impl Analysis {
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    pub fn input(&self) -> String {
+        format!("day{:02}.txt", self.day)
+    }
+
+    // path is either relative to the year's puzzle inputs directory or absolute
+    pub fn analyse(&self, path: &str, overrides: &[(String, u64)]) -> Result<String> {
+        let params = resolve_params(&self.name(), self.params, overrides)?;
+        Ok((self.analyse)(path, &params)?)
+    }
+}
+
+fn report<T, E>(result: std::result::Result<T, E>) -> std::result::Result<String, String>
+where
+    T: Display,
+    E: Debug,
+{
+    result.map(|x| x.to_string()).map_err(|e| format!("{e:?}"))
+}
+
+const DAY01_ANALYSIS_PARAMS: &[Param] = &[Param {
+    name: "top",
+    default: 10,
+}];
+
+// updates with more pages than this that have more than one order don't get their orders counted
+const DAY05_ANALYSIS_PARAMS: &[Param] = &[Param {
+    name: "max_pages",
+    default: 16,
+}];
+
+#[rustfmt::skip]
+pub static ANALYSES: &[Analysis] = &[
+    Analysis { year: 2024, day: 1, params: DAY01_ANALYSIS_PARAMS, analyse: |path, p| report(y2024::day01::analyse(path, p[0] as usize)) },
+    Analysis { year: 2024, day: 2, params: DAY02B_PARAMS, analyse: |path, p| report(y2024::day02::analyse_with_params(path, p)) },
+    Analysis { year: 2024, day: 3, params: &[], analyse: |path, _| report(y2024::day03::analyse(path)) },
+    Analysis { year: 2024, day: 4, params: &[], analyse: |path, _| report(y2024::day04::analyse(path)) },
+    Analysis { year: 2024, day: 5, params: DAY05_ANALYSIS_PARAMS, analyse: |path, p| report(y2024::day05::analyse(path, p[0] as usize)) },
+];
+
+pub fn find_analysis(year: u32, day: u32) -> Option<&'static Analysis> {
+    ANALYSES.iter().find(|a| a.year == year && a.day == day)
+}
+
+// solvers that are in SOLVERS but don't give the right answer for the real input yet, whether they never
+// finish, get it wrong, or stop at a todo!()
+pub const PLACEHOLDERS: &[(u32, u32, Part)] = &[
+    (2024, 17, Part::B),
+    (2024, 20, Part::B),
+    (2024, 21, Part::A),
+    (2024, 22, Part::B),
+    (2024, 24, Part::B),
+];
+
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Solved { answer: Answer, elapsed: Duration },
+    Failed { error: String, elapsed: Duration },
+    Panicked,
+    TimedOut,
+}
+
+// solver threads that haven't finished yet, including ones that were abandoned after timing out
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+struct RunningGuard;
+
+impl RunningGuard {
+    fn new() -> Self {
+        RUNNING.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+pub fn running_solvers() -> usize {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+// solvers can't be interrupted, so run them on their own thread and abandon it if it takes too long
+pub fn solve_with_timeout(solver: &'static Solver, path: &str, overrides: &[(String, u64)], timeout: Duration) -> Outcome {
+    match solver.resolve_params(overrides) {
+        Ok(params) => call_with_timeout(solver.solve, path, params, timeout),
+        Err(e) => Outcome::Failed {
+            error: e.0,
+            elapsed: Duration::ZERO,
+        },
+    }
+}
+
+fn call_with_timeout(solve: Solve, path: &str, params: Vec<u64>, timeout: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    {
+        let path = path.to_string();
+        let guard = RunningGuard::new();
+        thread::spawn(move || {
+            let _guard = guard;
+            let start = Instant::now();
+            let result = solve(&path, &params);
+            let _ = sender.send((result, start.elapsed()));
+        });
+    }
+    match receiver.recv_timeout(timeout) {
+        Ok((Ok(answer), elapsed)) => Outcome::Solved { answer, elapsed },
+        Ok((Err(error), elapsed)) => Outcome::Failed { error, elapsed },
+        // the sender only goes away without sending if the solver panicked
+        Err(RecvTimeoutError::Disconnected) => Outcome::Panicked,
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+    }
+}
+
+// the year anything that doesn't say otherwise means
+pub const DEFAULT_YEAR: u32 = 2024;
+
+// 2024 predates having more than one year, so its inputs are at the top level
+pub fn puzzle_inputs_dir(year: u32) -> PathBuf {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("puzzle-inputs");
+    match year {
+        2024 => dir,
+        _ => dir.join(year.to_string()),
+    }
+}
+
+pub fn years() -> Vec<u32> {
+    let mut result = SOLVERS.iter().map(|s| s.year).collect::<Vec<_>>();
+    result.sort();
+    result.dedup();
+    result
+}
+
+pub fn find(day: u32, part: Part) -> Option<&'static Solver> {
+    find_in_year(DEFAULT_YEAR, day, part)
+}
+
+pub fn find_in_year(year: u32, day: u32, part: Part) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+// accepts names like "day05b", "05b", or "5b", optionally with a year first like "2024/day05b"
+pub fn find_by_name(name: &str) -> Result<&'static Solver> {
+    let (year, name) = match name.trim().split_once('/') {
+        Some((year, name)) => (
+            year.parse::<u32>().map_err(|_| format!("invalid year: {year}"))?,
+            name,
+        ),
+        None => (DEFAULT_YEAR, name.trim()),
+    };
+    let name = name.trim_start_matches("day");
+    if name.len() < 2 {
+        Err(format!("expected a day and part, like day05b, got {name}"))?;
+    }
+    let (day, part) = name.split_at(name.len() - 1);
+    let day = day
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day: {day}"))?;
+    let part = Part::try_from(part)?;
+    Ok(find_in_year(year, day, part).ok_or(format!("no solver for {year} day {day} part {part}"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_find_by_name() {
+        assert_eq!(find_by_name("day05b").unwrap().name(), "day05b");
+        assert_eq!(find_by_name("14a").unwrap().params.len(), 2);
+        assert!(find_by_name("day21b").is_err());
+        assert!(find_by_name("day05c").is_err());
+        assert_eq!(find_by_name("2024/day05b").unwrap().name(), "day05b");
+        assert!(find_by_name("1999/day05b").is_err());
+        assert!(find_by_name("x/day05b").is_err());
+    }
+
+    #[test]
+    pub fn test_solve() {
+        let solver = find_by_name("day14a").unwrap();
+        assert_eq!(
+            solver
+                .solve(
+                    "day14-sample.txt",
+                    &[("width".to_string(), 11), ("height".to_string(), 7)]
+                )
+                .unwrap(),
+            "12"
+        );
+        assert!(solver.solve("day14-sample.txt", &[("depth".to_string(), 1)]).is_err());
+    }
+
+    #[test]
+    pub fn test_solve_with_timeout() {
+        let solver = find_by_name("day01a").unwrap();
+        assert!(matches!(
+            solve_with_timeout(solver, "day01-sample.txt", &[], Duration::from_secs(60)),
+            Outcome::Solved { answer, .. } if answer == 11
+        ));
+        assert!(matches!(
+            solve_with_timeout(solver, "does-not-exist.txt", &[], Duration::from_secs(60)),
+            Outcome::Failed { .. }
+        ));
+        let solver = find_by_name("day09b").unwrap();
+        assert!(matches!(
+            solve_with_timeout(solver, "day09.txt", &[], Duration::from_micros(1)),
+            Outcome::TimedOut
+        ));
+        // still todo!(), which it reaches right away on an empty input
+        let path = std::env::temp_dir().join(format!("aoc-runner-test-{}.txt", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+        let solver = find_by_name("day24b").unwrap();
+        assert!(matches!(
+            solve_with_timeout(solver, path.to_str().unwrap(), &[], Duration::from_secs(60)),
+            Outcome::Panicked
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
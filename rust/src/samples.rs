@@ -0,0 +1,377 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::runner::Part;
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+// one <pre><code> block from the puzzle description, and what each part expects for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+    pub file_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Extracted {
+    pub day: u32,
+    pub samples: Vec<Sample>,
+    // anything a human should double check, e.g. several candidate answers for one block
+    pub ambiguities: Vec<String>,
+}
+
+#[derive(Debug)]
+enum Event {
+    Block(String),
+    Answer(String),
+}
+
+// the page has one <article> per part that's been unlocked
+fn articles(html: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        result.push(&rest[start..start + end]);
+        rest = &rest[start + end + "</article>".len()..];
+    }
+    if result.is_empty() {
+        result.push(html);
+    }
+    result
+}
+
+// every sample block and every emphasised bit of inline code, in the order they appear
+fn events(article: &str) -> Vec<Event> {
+    let mut found = Vec::new();
+    for (open, close, is_block) in [
+        ("<pre><code>", "</code></pre>", true),
+        ("<code><em>", "</em></code>", false),
+        ("<em><code>", "</code></em>", false),
+    ] {
+        let mut offset = 0;
+        while let Some(start) = article[offset..].find(open) {
+            let start = offset + start;
+            let Some(end) = article[start..].find(close) else {
+                break;
+            };
+            let end = start + end;
+            found.push((start, end + close.len(), is_block, text(&article[start + open.len()..end])));
+            offset = end + close.len();
+        }
+    }
+    found.sort_by_key(|(start, ..)| *start);
+
+    // answers inside a block are highlights in a diagram, not answers
+    let mut result = Vec::new();
+    let mut block_end = 0;
+    for (start, end, is_block, text) in found {
+        if is_block {
+            block_end = end;
+            result.push(Event::Block(text));
+        } else if start >= block_end {
+            result.push(Event::Answer(text));
+        }
+    }
+    result
+}
+
+// strips tags and decodes the few entities that show up in puzzle text
+fn text(html: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        };
+    }
+    result
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn describe(day: u32, part: Part, block: usize) -> String {
+    format!("day{day:02}{part} block {}", block + 1)
+}
+
+// picks the last emphasised answer after each block, since the text usually works through intermediate values first
+fn pick(description: &str, candidates: &[String], ambiguities: &mut Vec<String>) -> Option<String> {
+    let mut distinct = Vec::new();
+    for candidate in candidates {
+        if !distinct.contains(candidate) {
+            distinct.push(candidate.clone());
+        }
+    }
+    match distinct.as_slice() {
+        [] => None,
+        [only] => Some(only.clone()),
+        [.., last] => {
+            ambiguities.push(format!(
+                "{description}: picked {last} out of candidates {}",
+                distinct.join(", ")
+            ));
+            Some(last.clone())
+        }
+    }
+}
+
+pub fn extract(day: u32, html: &str) -> Extracted {
+    let mut samples: Vec<(String, Vec<(Part, String)>)> = Vec::new();
+    let mut ambiguities = Vec::new();
+
+    for (article, part) in articles(html).into_iter().zip([Part::A, Part::B]) {
+        let events = events(article);
+        let mut index = 0;
+
+        // part two usually refers back to an example from part one before showing any new ones
+        let leading = events
+            .iter()
+            .take_while(|e| matches!(e, Event::Answer(_)))
+            .map(|e| match e {
+                Event::Answer(answer) => answer.clone(),
+                Event::Block(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        if part == Part::B && !leading.is_empty() {
+            let description = format!("day{day:02}{part} answers before any block");
+            match samples.len() {
+                0 => ambiguities.push(format!("{description}: no earlier block to attach them to")),
+                n => {
+                    if n > 1 {
+                        ambiguities.push(format!("{description}: attached to the last of {n} part a blocks"));
+                    }
+                    if let Some(answer) = pick(&description, &leading, &mut ambiguities) {
+                        samples[n - 1].1.push((part, answer));
+                    }
+                }
+            }
+        }
+
+        let mut iter = events.into_iter().skip(leading.len()).peekable();
+        while let Some(event) = iter.next() {
+            let Event::Block(input) = event else {
+                continue;
+            };
+            let mut candidates = Vec::new();
+            while let Some(Event::Answer(answer)) = iter.peek() {
+                candidates.push(answer.clone());
+                iter.next();
+            }
+            let description = describe(day, part, index);
+            index += 1;
+            let Some(answer) = pick(&description, &candidates, &mut ambiguities) else {
+                ambiguities.push(format!("{description}: no answer follows it, skipped"));
+                continue;
+            };
+            match samples.iter_mut().find(|(existing, _)| *existing == input) {
+                Some((_, expected)) => expected.push((part, answer)),
+                None => samples.push((input, vec![(part, answer)])),
+            };
+        }
+    }
+
+    // shared samples are named dayNN-sample, ones only one part uses dayNNa-sample or dayNNb-sample
+    let group = |expected: &[(Part, String)]| match expected {
+        [(Part::A, _)] => "a",
+        [(Part::B, _)] => "b",
+        _ => "",
+    };
+    let mut counts = BTreeMap::new();
+    for (_, expected) in samples.iter() {
+        *counts.entry(group(expected)).or_insert(0) += 1;
+    }
+    let mut seen = BTreeMap::new();
+    let samples = samples
+        .into_iter()
+        .map(|(input, expected)| {
+            let group = group(&expected);
+            let k = seen.entry(group).or_insert(0);
+            *k += 1;
+            let file_name = match counts[group] {
+                1 => format!("day{day:02}{group}-sample.txt"),
+                _ => format!("day{day:02}{group}-sample{k}.txt"),
+            };
+            Sample {
+                input,
+                expected,
+                file_name,
+            }
+        })
+        .collect();
+
+    Extracted {
+        day,
+        samples,
+        ambiguities,
+    }
+}
+
+pub fn manifest_path(dir: &Path) -> PathBuf {
+    dir.join("samples.tsv")
+}
+
+// manifest lines are "file\tpart\texpected", keyed by file and part
+fn read_manifest(path: &Path) -> Result<BTreeMap<(String, String), String>> {
+    let mut result = BTreeMap::new();
+    if path.exists() {
+        for (i, line) in std::fs::read_to_string(path)?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match line.split('\t').collect::<Vec<_>>().as_slice() {
+                [file, part, expected] => {
+                    result.insert((file.to_string(), part.to_string()), expected.to_string());
+                }
+                _ => Err(format!("{}:{}: expected 3 tab separated fields", path.display(), i + 1))?,
+            };
+        }
+    }
+    Ok(result)
+}
+
+// writes each sample and its manifest entries, never overwriting a sample file that says something else
+pub fn write(extracted: &mut Extracted, dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    let manifest = manifest_path(dir);
+    let mut entries = read_manifest(&manifest)?;
+    let mut written = Vec::new();
+    for sample in extracted.samples.iter() {
+        let path = dir.join(&sample.file_name);
+        if path.exists() {
+            if std::fs::read_to_string(&path)? != sample.input {
+                extracted
+                    .ambiguities
+                    .push(format!("{} already exists with different contents, left alone", sample.file_name));
+                continue;
+            }
+        } else {
+            std::fs::write(&path, &sample.input)?;
+            written.push(path);
+        }
+        for (part, expected) in sample.expected.iter() {
+            let key = (sample.file_name.clone(), part.to_string());
+            if let Some(existing) = entries.get(&key) {
+                if existing != expected {
+                    extracted.ambiguities.push(format!(
+                        "{} part {part}: manifest said {existing}, now {expected}",
+                        sample.file_name
+                    ));
+                }
+            }
+            entries.insert(key, expected.clone());
+        }
+    }
+    let contents = entries
+        .iter()
+        .map(|((file, part), expected)| format!("{file}\t{part}\t{expected}\n"))
+        .collect::<String>();
+    std::fs::write(&manifest, contents)?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(name)).unwrap()
+    }
+
+    fn expected(sample: &Sample) -> Vec<(String, String)> {
+        sample
+            .expected
+            .iter()
+            .map(|(part, answer)| (part.to_string(), answer.clone()))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_shared_sample() {
+        let extracted = extract(1, &fixture("day01.html"));
+        assert_eq!(extracted.samples.len(), 1);
+        let sample = &extracted.samples[0];
+        assert_eq!(sample.file_name, "day01-sample.txt");
+        assert_eq!(sample.input, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n");
+        assert_eq!(
+            expected(sample),
+            vec![("a".to_string(), "11".to_string()), ("b".to_string(), "31".to_string())]
+        );
+        // both walkthroughs emphasise intermediate values before the total
+        assert_eq!(extracted.ambiguities.len(), 2);
+        assert!(extracted.ambiguities[0].contains("picked 11 out of candidates 2, 1, 0, 5, 11"));
+        assert!(extracted.ambiguities[1].contains("picked 31"));
+    }
+
+    #[test]
+    pub fn test_separate_samples() {
+        let extracted = extract(4, &fixture("day04.html"));
+        let names = extracted.samples.iter().map(|s| s.file_name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["day04a-sample1.txt", "day04a-sample2.txt", "day04b-sample.txt"]);
+        assert_eq!(extracted.samples[0].input, "..X...\n.SAMX.\n.A..A.\nXMAS.S\n.X....\n");
+        assert_eq!(expected(&extracted.samples[0]), vec![("a".to_string(), "4".to_string())]);
+        assert_eq!(expected(&extracted.samples[1]), vec![("a".to_string(), "18".to_string())]);
+        assert_eq!(expected(&extracted.samples[2]), vec![("b".to_string(), "9".to_string())]);
+        assert_eq!(extracted.samples[2].input, "M.S\n.A.\nM.S\n");
+        // the diagram of found words has no answer of its own
+        assert!(extracted.ambiguities.iter().any(|a| a.contains("day04a block 3: no answer")));
+    }
+
+    #[test]
+    pub fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-samples-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut extracted = extract(4, &fixture("day04.html"));
+        assert_eq!(write(&mut extracted, &dir).unwrap().len(), 3);
+        assert_eq!(
+            std::fs::read_to_string(manifest_path(&dir)).unwrap(),
+            "day04a-sample1.txt\ta\t4\nday04a-sample2.txt\ta\t18\nday04b-sample.txt\tb\t9\n"
+        );
+
+        // running again changes nothing, a file that differs is reported rather than replaced
+        std::fs::write(dir.join("day04b-sample.txt"), "edited by hand\n").unwrap();
+        let mut extracted = extract(4, &fixture("day04.html"));
+        let before = extracted.ambiguities.len();
+        assert_eq!(write(&mut extracted, &dir).unwrap().len(), 0);
+        assert_eq!(extracted.ambiguities.len(), before + 1);
+        assert_eq!(std::fs::read_to_string(dir.join("day04b-sample.txt")).unwrap(), "edited by hand\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    pub fn test_text() {
+        assert_eq!(text("a &lt;<em>b</em>&gt; &amp;lt;"), "a <b> &lt;");
+    }
+}
@@ -0,0 +1,320 @@
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::runner::{self, Part};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+// where the sources and inputs for a year live
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub src_dir: PathBuf,
+    pub inputs_dir: PathBuf,
+}
+
+impl Layout {
+    pub fn for_year(year: u32) -> Self {
+        Self {
+            src_dir: Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            inputs_dir: runner::puzzle_inputs_dir(year),
+        }
+    }
+}
+
+const SOLVERS_START: &str = "pub static SOLVERS: &[Solver] = &[\n";
+
+fn template(year: u32, day: u32) -> String {
+    let year_dir = match year {
+        2024 => String::new(),
+        _ => format!("\n            .join(\"{year}\")"),
+    };
+    format!(
+        r#"use std::{{
+    env,
+    fs::File,
+    io::{{BufRead, BufReader}},
+    num::ParseIntError,
+    path::Path,
+}};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {{
+    fn from(value: &str) -> Self {{
+        Self(value.to_string())
+    }}
+}}
+
+impl From<String> for Error {{
+    fn from(value: String) -> Self {{
+        Self(value)
+    }}
+}}
+
+impl From<std::io::Error> for Error {{
+    fn from(value: std::io::Error) -> Self {{
+        Self(format!("std::io::Error({{value:?}})"))
+    }}
+}}
+
+impl From<ParseIntError> for Error {{
+    fn from(value: core::num::ParseIntError) -> Self {{
+        Self(format!("core::num::ParseIntError({{value:?}})"))
+    }}
+}}
+
+pub(crate) fn do_it(path: &str) -> Result<u64> {{
+    let lines = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs"){year_dir}
+            .join(path),
+    )?)
+    .lines()
+    .map(|line| Ok(line?.trim().to_string()))
+    .collect::<Result<Vec<_>>>()?;
+
+    Err(format!("not solved yet, read {{}} lines", lines.len()).into())
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample() {{
+        assert_eq!(do_it("day{day:02}-sample.txt").unwrap(), 0);
+    }}
+
+    #[test]
+    pub fn test_real() {{
+        assert_eq!(do_it("day{day:02}.txt").unwrap(), 0);
+    }}
+}}
+"#
+    )
+}
+
+fn solver_line(year: u32, day: u32, part: Part) -> String {
+    format!(
+        "    Solver {{ year: {year}, day: {day}, part: Part::{}, params: &[], solve: |path, _| answer(y{year}::day{day:02}{part}::do_it(path)) }},\n",
+        part.to_string().to_uppercase()
+    )
+}
+
+// inserts a line before the first existing line that sorts after it, according to key
+fn insert_sorted<K: Ord>(lines: &mut Vec<String>, line: String, key: impl Fn(&str) -> Option<K>) {
+    let new_key = key(&line);
+    let index = lines
+        .iter()
+        .position(|existing| matches!((key(existing), &new_key), (Some(a), Some(b)) if a > *b))
+        .or_else(|| lines.iter().rposition(|existing| key(existing).is_some()).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(index, line);
+}
+
+// adds the two solvers for a day to the table in runner.rs, keeping it ordered by year, day, and part
+fn register(runner_source: &str, year: u32, day: u32) -> Result<String> {
+    let start = runner_source
+        .find(SOLVERS_START)
+        .ok_or("couldn't find the SOLVERS table in runner.rs")?
+        + SOLVERS_START.len();
+    let end = start
+        + runner_source[start..]
+            .find("\n];")
+            .ok_or("couldn't find the end of the SOLVERS table in runner.rs")?
+        + 1;
+    let r = Regex::new(r"^\s*Solver \{ year: (\d+), day: (\d+), part: Part::([AB]),")?;
+    let key = |line: &str| {
+        r.captures(line)
+            .map(|c| (c[1].parse::<u32>().unwrap(), c[2].parse::<u32>().unwrap(), c[3].to_string()))
+    };
+
+    let mut lines = runner_source[start..end]
+        .split_inclusive('\n')
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    if lines
+        .iter()
+        .any(|line| matches!(key(line), Some((y, d, _)) if (y, d) == (year, day)))
+    {
+        Err(format!("{year} day {day} is already registered in runner.rs"))?;
+    }
+    for part in [Part::A, Part::B] {
+        insert_sorted(&mut lines, solver_line(year, day, part), key);
+    }
+    Ok(format!(
+        "{}{}{}",
+        &runner_source[..start],
+        lines.concat(),
+        &runner_source[end..]
+    ))
+}
+
+// creates the solution files for a day from a template, registers them, and adds empty inputs
+pub fn scaffold(layout: &Layout, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        Err(format!("no such day: {day}"))?;
+    }
+    let year_dir = layout.src_dir.join(format!("y{year}"));
+    let mod_path = year_dir.join("mod.rs");
+    let lib_path = layout.src_dir.join("lib.rs");
+    let runner_path = layout.src_dir.join("runner.rs");
+    let day_paths = [Part::A, Part::B].map(|part| (part, year_dir.join(format!("day{day:02}{part}.rs"))));
+    if let Some((_, path)) = day_paths.iter().find(|(_, path)| path.exists()) {
+        Err(format!("{} already exists", path.display()))?;
+    }
+    // work out every change before writing anything, so a failure leaves the tree alone
+    let runner_source = register(&std::fs::read_to_string(&runner_path)?, year, day)?;
+    let mut touched = Vec::new();
+
+    if !mod_path.exists() {
+        let lib_source = std::fs::read_to_string(&lib_path)?;
+        let mut lines = lib_source.split_inclusive('\n').map(|l| l.to_string()).collect();
+        let key = |line: &str| line.strip_prefix("mod y").map(|rest| rest.trim().to_string());
+        insert_sorted(&mut lines, format!("mod y{year};\n"), key);
+        std::fs::create_dir_all(&year_dir)?;
+        std::fs::write(&mod_path, "")?;
+        std::fs::write(&lib_path, lines.concat())?;
+        touched.push(lib_path);
+    }
+    let mut lines = std::fs::read_to_string(&mod_path)?
+        .split_inclusive('\n')
+        .map(|l| l.to_string())
+        .collect();
+    let key = |line: &str| line.strip_prefix("pub(crate) mod ").map(|rest| rest.trim().to_string());
+    for (part, _) in day_paths.iter() {
+        insert_sorted(&mut lines, format!("pub(crate) mod day{day:02}{part};\n"), key);
+    }
+    std::fs::write(&mod_path, lines.concat())?;
+    touched.push(mod_path);
+
+    for (_, path) in day_paths {
+        std::fs::write(&path, template(year, day))?;
+        touched.push(path);
+    }
+    std::fs::write(&runner_path, runner_source)?;
+    touched.push(runner_path);
+
+    std::fs::create_dir_all(&layout.inputs_dir)?;
+    for name in [format!("day{day:02}-sample.txt"), format!("day{day:02}.txt")] {
+        let path = layout.inputs_dir.join(name);
+        if !path.exists() {
+            std::fs::write(&path, "")?;
+            touched.push(path);
+        }
+    }
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUNNER: &str = "use crate::*;
+
+#[rustfmt::skip]
+pub static SOLVERS: &[Solver] = &[
+    Solver { year: 2024, day: 1, part: Part::A, params: &[], solve: |path, _| answer(y2024::day01a::do_it(path)) },
+    Solver { year: 2024, day: 1, part: Part::B, params: &[], solve: |path, _| answer(y2024::day01b::do_it(path)) },
+];
+
+pub fn find() {}
+";
+
+    fn layout(name: &str) -> Layout {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let src_dir = root.join("src");
+        std::fs::create_dir_all(src_dir.join("y2024")).unwrap();
+        std::fs::write(src_dir.join("lib.rs"), "mod y2024;\n\npub mod bench;\n").unwrap();
+        std::fs::write(src_dir.join("runner.rs"), RUNNER).unwrap();
+        std::fs::write(src_dir.join("y2024").join("mod.rs"), "pub(crate) mod day01a;\npub(crate) mod day01b;\n").unwrap();
+        Layout {
+            src_dir,
+            inputs_dir: root.join("puzzle-inputs").join("2025"),
+        }
+    }
+
+    fn read(path: PathBuf) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    pub fn test_new_year() {
+        let layout = layout("new-year");
+        scaffold(&layout, 2025, 3).unwrap();
+        scaffold(&layout, 2025, 2).unwrap();
+
+        assert_eq!(read(layout.src_dir.join("lib.rs")), "mod y2024;\nmod y2025;\n\npub mod bench;\n");
+        assert_eq!(
+            read(layout.src_dir.join("y2025").join("mod.rs")),
+            "pub(crate) mod day02a;\npub(crate) mod day02b;\npub(crate) mod day03a;\npub(crate) mod day03b;\n"
+        );
+        let runner = read(layout.src_dir.join("runner.rs"));
+        let days = runner
+            .lines()
+            .filter_map(|line| line.split("answer(").nth(1))
+            .map(|call| call.split("::do_it").next().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            days,
+            vec!["y2024::day01a", "y2024::day01b", "y2025::day02a", "y2025::day02b", "y2025::day03a", "y2025::day03b"]
+        );
+        assert!(runner.ends_with("];\n\npub fn find() {}\n"));
+
+        let source = read(layout.src_dir.join("y2025").join("day03b.rs"));
+        assert!(source.contains(".join(\"puzzle-inputs\")\n            .join(\"2025\")\n"));
+        assert!(source.contains("do_it(\"day03-sample.txt\")"));
+        assert_eq!(read(layout.inputs_dir.join("day03.txt")), "");
+        assert_eq!(read(layout.inputs_dir.join("day03-sample.txt")), "");
+        std::fs::remove_dir_all(layout.src_dir.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    pub fn test_refuses_existing_days() {
+        let layout = layout("existing");
+        let before = read(layout.src_dir.join("runner.rs"));
+        assert!(scaffold(&layout, 2024, 1).is_err());
+        assert!(scaffold(&layout, 2024, 26).is_err());
+        assert_eq!(read(layout.src_dir.join("runner.rs")), before);
+
+        scaffold(&layout, 2024, 2).unwrap();
+        assert!(scaffold(&layout, 2024, 2).is_err());
+        assert_eq!(read(layout.src_dir.join("lib.rs")), "mod y2024;\n\npub mod bench;\n");
+        let source = read(layout.src_dir.join("y2024").join("day02a.rs"));
+        assert!(source.contains(".join(\"puzzle-inputs\")\n            .join(path)"));
+        std::fs::remove_dir_all(layout.src_dir.parent().unwrap()).unwrap();
+    }
+}
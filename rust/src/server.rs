@@ -0,0 +1,435 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    json::Value,
+    runner::{self, Outcome, Part, Solver, SOLVERS},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+const MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    // how long a single solve gets before the request gives up on it
+    pub timeout: Duration,
+    // solver threads keep running after a timeout, so refuse new work once this many are still going
+    pub max_running: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_running: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, kind: &str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: Value::object(vec![("error", kind.into()), ("message", message.into().into())]),
+        }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+fn describe_solver(solver: &Solver) -> Value {
+    Value::object(vec![
+        ("name", solver.name().into()),
+        ("year", solver.year.into()),
+        ("part", solver.part.to_string().into()),
+        (
+            "params",
+            Value::Array(
+                solver
+                    .params
+                    .iter()
+                    .map(|p| Value::object(vec![("name", p.name.into()), ("type", "u64".into()), ("default", p.default.into())]))
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+fn describe_day(day: u32) -> Value {
+    Value::object(vec![
+        ("day", day.into()),
+        (
+            "parts",
+            Value::Array(
+                SOLVERS
+                    .iter()
+                    .filter(|s| s.year == runner::DEFAULT_YEAR && s.day == day)
+                    .map(describe_solver)
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+fn days() -> Vec<u32> {
+    let mut result = SOLVERS
+        .iter()
+        .filter(|s| s.year == runner::DEFAULT_YEAR)
+        .map(|s| s.day)
+        .collect::<Vec<_>>();
+    result.dedup();
+    result
+}
+
+// the body is {"input": "...puzzle text...", "params": {"width": 11, "height": 7}}, params being optional
+fn parse_solve_body(solver: &Solver, body: &str) -> std::result::Result<(String, Vec<(String, u64)>), Response> {
+    let body = Value::parse(body).map_err(|e| Response::error(400, "bad_request", format!("invalid json: {}", e.0)))?;
+    let input = body
+        .get("input")
+        .and_then(|x| x.as_str())
+        .ok_or(Response::error(400, "bad_request", "missing string field \"input\""))?
+        .to_string();
+    let mut params = Vec::new();
+    match body.get("params") {
+        None | Some(Value::Null) => {}
+        Some(Value::Object(fields)) => {
+            for (name, value) in fields.iter() {
+                let value = value.as_u64().ok_or(Response::error(
+                    400,
+                    "bad_request",
+                    format!("parameter {name} must be a non-negative integer"),
+                ))?;
+                params.push((name.clone(), value));
+            }
+        }
+        Some(_) => Err(Response::error(400, "bad_request", "\"params\" must be an object"))?,
+    };
+    solver
+        .resolve_params(&params)
+        .map_err(|e| Response::error(400, "bad_request", e.0))?;
+    Ok((input, params))
+}
+
+fn solve(solver: &'static Solver, body: &str, config: &Config) -> Response {
+    let (input, params) = match parse_solve_body(solver, body) {
+        Ok(x) => x,
+        Err(response) => return response,
+    };
+    if runner::running_solvers() >= config.max_running {
+        return Response::error(
+            503,
+            "busy",
+            format!("{} solvers are still running, try again later", runner::running_solvers()),
+        );
+    }
+
+    // solvers read their input from a file
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!(
+        "aoc-serve-{}-{}.txt",
+        std::process::id(),
+        NEXT_FILE.fetch_add(1, Ordering::SeqCst)
+    ));
+    if let Err(e) = std::fs::write(&path, input) {
+        return Response::error(500, "internal", format!("failed to write input: {e}"));
+    }
+    let outcome = runner::solve_with_timeout(solver, &path.to_string_lossy(), &params, config.timeout);
+    let _ = std::fs::remove_file(&path);
+
+    let base = vec![("day", solver.day.into()), ("part", solver.part.to_string().into())];
+    match outcome {
+        Outcome::Solved { answer, elapsed } => Response::ok(Value::object(
+            base.into_iter()
+                .chain([("answer", answer.to_string().into()), ("elapsed_ms", (elapsed.as_secs_f64() * 1000.0).into())])
+                .collect(),
+        )),
+        Outcome::Failed { error, elapsed } => Response {
+            status: 422,
+            body: Value::object(
+                base.into_iter()
+                    .chain([
+                        ("error", "failed".into()),
+                        ("message", error.into()),
+                        ("elapsed_ms", (elapsed.as_secs_f64() * 1000.0).into()),
+                    ])
+                    .collect(),
+            ),
+        },
+        Outcome::Panicked => Response::error(500, "panicked", format!("{} panicked", solver.name())),
+        Outcome::TimedOut => Response::error(
+            504,
+            "timeout",
+            format!("{} did not finish within {:?}", solver.name(), config.timeout),
+        ),
+    }
+}
+
+pub fn handle(request: &Request, config: &Config) -> Response {
+    let path = request.path.split('?').next().unwrap_or("");
+    let segments = path.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => Response::ok(Value::object(vec![(
+            "days",
+            Value::Array(days().into_iter().map(describe_day).collect()),
+        )])),
+        ("GET", ["days", day]) => match day.parse::<u32>() {
+            Ok(day) if days().contains(&day) => Response::ok(describe_day(day)),
+            _ => Response::error(404, "not_found", format!("no such day: {day}")),
+        },
+        ("POST", ["solve", day, part]) => {
+            let solver = day
+                .parse::<u32>()
+                .ok()
+                .zip(Part::try_from(*part).ok())
+                .and_then(|(day, part)| runner::find(day, part));
+            match solver {
+                Some(solver) => solve(solver, &request.body, config),
+                None => Response::error(404, "not_found", format!("no solver for day {day} part {part}")),
+            }
+        }
+        (_, ["days"]) | (_, ["days", _]) | (_, ["solve", _, _]) => {
+            Response::error(405, "method_not_allowed", format!("{} not allowed on {path}", request.method))
+        }
+        _ => Response::error(404, "not_found", format!("no route for {path}")),
+    }
+}
+
+// the request line and the headers, as method, path and content length
+fn read_head(reader: &mut impl BufRead) -> Result<(String, String, usize)> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => Err(format!("bad request line: {line:?}"))?,
+    };
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        reader.read_line(&mut line)?;
+        let header = line.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("bad content-length: {value}"))?;
+            }
+        }
+    }
+    Ok((method, path, content_length))
+}
+
+fn read_body(reader: &mut impl BufRead, content_length: usize) -> Result<String> {
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(String::from_utf8(body).map_err(|_| "body is not utf-8")?)
+}
+
+// the request, or the response to send back instead when it can't be read
+fn read_request(stream: &TcpStream) -> std::result::Result<Request, Response> {
+    let bad_request = |e: Error| Response::error(400, "bad_request", e.0);
+    let mut reader = BufReader::new(stream);
+    let (method, path, content_length) = read_head(&mut reader).map_err(bad_request)?;
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(
+            413,
+            "payload_too_large",
+            format!("body too large: {content_length} bytes, the most is {MAX_BODY_BYTES}"),
+        ));
+    }
+    let body = read_body(&mut reader, content_length).map_err(bad_request)?;
+    Ok(Request { method, path, body })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> Result<()> {
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, config: &Config) -> Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    let response = match read_request(&stream) {
+        Ok(request) => handle(&request, config),
+        Err(response) => response,
+    };
+    write_response(&mut stream, &response)
+}
+
+// one thread per connection, runs until the listener fails
+pub fn serve(listener: TcpListener, config: Config) -> Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let config = config.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, &config) {
+                eprintln!("error handling connection: {}", e.0);
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    pub fn test_days() {
+        let response = handle(&request("GET", "/days", ""), &Config::default());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.get("days").unwrap().as_array().unwrap().len(), 25);
+
+        let response = handle(&request("GET", "/days/18", ""), &Config::default());
+        let parts = response.body.get("parts").unwrap().as_array().unwrap();
+        assert_eq!(parts[0].get("params").unwrap().as_array().unwrap().len(), 3);
+        assert_eq!(parts[1].get("params").unwrap().as_array().unwrap().len(), 2);
+
+        assert_eq!(handle(&request("GET", "/days/26", ""), &Config::default()).status, 404);
+        assert_eq!(handle(&request("POST", "/days", ""), &Config::default()).status, 405);
+    }
+
+    #[test]
+    pub fn test_solve() {
+        let input = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("puzzle-inputs")
+                .join("day14-sample.txt"),
+        )
+        .unwrap();
+        let body = Value::object(vec![
+            ("input", input.into()),
+            ("params", Value::object(vec![("width", 11u64.into()), ("height", 7u64.into())])),
+        ]);
+        let response = handle(&request("POST", "/solve/14/a", &body.to_string()), &Config::default());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.get("answer").unwrap().as_str(), Some("12"));
+    }
+
+    #[test]
+    pub fn test_solve_errors() {
+        let config = Config::default();
+        assert_eq!(handle(&request("POST", "/solve/21/b", "{}"), &config).status, 404);
+        assert_eq!(handle(&request("POST", "/solve/1/a", "not json"), &config).status, 400);
+        assert_eq!(handle(&request("POST", "/solve/1/a", "{}"), &config).status, 400);
+        assert_eq!(
+            handle(&request("POST", "/solve/1/a", r#"{"input":"1 2","params":{"width":3}}"#), &config).status,
+            400
+        );
+        assert_eq!(handle(&request("POST", "/solve/1/a", r#"{"input":"oops"}"#), &config).status, 422);
+        assert_eq!(handle(&request("POST", "/solve/24/b", r#"{"input":""}"#), &config).status, 500);
+    }
+
+    #[test]
+    pub fn test_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, Config::default()));
+
+        let body = r#"{"input":"3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"}"#;
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /solve/1/b HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        assert_eq!(Value::parse(body).unwrap().get("answer").unwrap().as_str(), Some("31"));
+
+        // turned away before the body is read
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /solve/1/b HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"), "{response}");
+    }
+}
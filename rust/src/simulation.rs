@@ -0,0 +1,293 @@
+// something that moves forward one step at a time, like the guard in day 6 or the warehouse robot in day 15
+// anything a step consumes, like a list of moves, belongs in the simulation so step needs no arguments
+pub trait Simulation {
+    // enough to put the simulation back how it was, leaving out whatever never changes, like the walls
+    type Snapshot: Clone + PartialEq;
+    type Error: From<String>;
+
+    fn step(&mut self) -> Result<(), Self::Error>;
+
+    fn is_finished(&self) -> bool;
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
+    // steps until finished, or until limit steps if that comes first, and says how many steps it took
+    fn run(&mut self, limit: Option<usize>) -> Result<usize, Self::Error> {
+        let mut steps = 0;
+        while !self.is_finished() && limit.is_none_or(|limit| steps < limit) {
+            self.step()?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+}
+
+// runs a simulation while keeping a snapshot every so many steps, so any step it has been through can be
+// rebuilt by restoring the checkpoint before it and replaying from there
+// closer checkpoints make going back quicker and take more memory
+pub struct Recorder<S: Simulation> {
+    simulation: S,
+    step: usize,
+    spacing: usize,
+    // checkpoints[i] is the snapshot at step i * spacing
+    checkpoints: Vec<S::Snapshot>,
+}
+
+impl<S: Simulation> Recorder<S> {
+    pub fn new(simulation: S, spacing: usize) -> Self {
+        let checkpoints = vec![simulation.snapshot()];
+        Self {
+            simulation,
+            step: 0,
+            spacing: spacing.max(1),
+            checkpoints,
+        }
+    }
+
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    pub fn into_simulation(self) -> S {
+        self.simulation
+    }
+
+    // how many steps the simulation is from the start
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    // the furthest step that's been reached so far
+    pub fn recorded_steps(&self) -> usize {
+        self.step.max((self.checkpoints.len() - 1) * self.spacing)
+    }
+
+    // one step forward, false if the simulation had already finished
+    pub fn advance(&mut self) -> Result<bool, S::Error> {
+        if self.simulation.is_finished() {
+            return Ok(false);
+        }
+        self.simulation.step()?;
+        self.step += 1;
+        if self.step.is_multiple_of(self.spacing) {
+            let index = self.step / self.spacing;
+            let snapshot = self.simulation.snapshot();
+            match self.checkpoints.get(index) {
+                // been here before, which is a chance to check the replay went the same way
+                Some(checkpoint) if *checkpoint != snapshot => Err(format!(
+                    "simulation isn't deterministic, step {} came out differently on replay",
+                    self.step
+                ))?,
+                Some(_) => {}
+                None => self.checkpoints.push(snapshot),
+            };
+        }
+        Ok(true)
+    }
+
+    // steps until finished, or until limit more steps if that comes first, and says how many steps it took
+    pub fn run(&mut self, limit: Option<usize>) -> Result<usize, S::Error> {
+        let mut steps = 0;
+        while limit.is_none_or(|limit| steps < limit) && self.advance()? {
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    // puts the simulation at step, going back via the nearest checkpoint or forward by stepping
+    pub fn rewind(&mut self, step: usize) -> Result<(), S::Error> {
+        let index = (step / self.spacing).min(self.checkpoints.len() - 1);
+        if step < self.step || index * self.spacing > self.step {
+            self.simulation.restore(&self.checkpoints[index]);
+            self.step = index * self.spacing;
+        }
+        while self.step < step {
+            if !self.advance()? {
+                Err(format!("simulation finished at step {}, before step {step}", self.step))?;
+            }
+        }
+        Ok(())
+    }
+
+    // calls visit with every step from..=to in order, leaving the simulation at to
+    pub fn replay(&mut self, from: usize, to: usize, mut visit: impl FnMut(usize, &S)) -> Result<(), S::Error> {
+        self.rewind(from)?;
+        visit(self.step, &self.simulation);
+        while self.step < to {
+            if !self.advance()? {
+                Err(format!("simulation finished at step {}, before step {to}", self.step))?;
+            }
+            visit(self.step, &self.simulation);
+        }
+        Ok(())
+    }
+
+    // the first recorded step where predicate holds, assuming once it holds it keeps holding
+    // leaves the simulation at that step if there is one, and at the last recorded step if not
+    pub fn bisect(&mut self, mut predicate: impl FnMut(&S) -> bool) -> Result<Option<usize>, S::Error> {
+        let last = self.recorded_steps();
+        self.rewind(last)?;
+        if !predicate(&self.simulation) {
+            return Ok(None);
+        }
+        // predicate is false before low (or low is 0) and true at high
+        let (mut low, mut high) = (0, last);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            self.rewind(middle)?;
+            if predicate(&self.simulation) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        self.rewind(high)?;
+        Ok(Some(high))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Error(String);
+
+    impl From<String> for Error {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+
+    // the collatz sequence from some number, finishing when it gets to 1
+    struct Collatz {
+        value: u64,
+        peak: u64,
+        // not part of the snapshot, so it shows how much work rewinding took
+        steps_taken: u64,
+    }
+
+    impl Simulation for Collatz {
+        type Snapshot = (u64, u64);
+        type Error = Error;
+
+        fn step(&mut self) -> Result<(), Error> {
+            self.steps_taken += 1;
+            self.value = if self.value.is_multiple_of(2) { self.value / 2 } else { 3 * self.value + 1 };
+            self.peak = self.peak.max(self.value);
+            Ok(())
+        }
+
+        fn is_finished(&self) -> bool {
+            self.value == 1
+        }
+
+        fn snapshot(&self) -> (u64, u64) {
+            (self.value, self.peak)
+        }
+
+        fn restore(&mut self, snapshot: &(u64, u64)) {
+            (self.value, self.peak) = *snapshot;
+        }
+    }
+
+    fn collatz(value: u64) -> Collatz {
+        Collatz {
+            value,
+            peak: value,
+            steps_taken: 0,
+        }
+    }
+
+    fn sequence(value: u64) -> Vec<u64> {
+        let mut result = vec![value];
+        let mut simulation = collatz(value);
+        while !simulation.is_finished() {
+            simulation.step().unwrap();
+            result.push(simulation.value);
+        }
+        result
+    }
+
+    #[test]
+    pub fn test_run() {
+        let mut simulation = collatz(27);
+        assert_eq!(simulation.run(Some(10)).unwrap(), 10);
+        assert_eq!(simulation.run(None).unwrap(), 101);
+        assert_eq!(simulation.run(None).unwrap(), 0);
+    }
+
+    #[test]
+    pub fn test_rewind_and_replay() {
+        let expected = sequence(27);
+        let mut recorder = Recorder::new(collatz(27), 16);
+        assert_eq!(recorder.run(None).unwrap(), 111);
+        assert_eq!(recorder.recorded_steps(), 111);
+
+        for step in [0, 15, 16, 17, 100, 3, 111, 64] {
+            recorder.rewind(step).unwrap();
+            assert_eq!(recorder.step(), step);
+            assert_eq!(recorder.simulation().value, expected[step]);
+        }
+        // going back replays at most spacing - 1 steps from the checkpoint
+        let before = recorder.simulation().steps_taken;
+        recorder.rewind(47).unwrap();
+        assert_eq!(recorder.simulation().steps_taken - before, 15);
+        assert!(recorder.rewind(112).is_err());
+
+        let mut visited = Vec::new();
+        recorder.replay(30, 35, |step, s| visited.push((step, s.value))).unwrap();
+        assert_eq!(visited, (30..=35).map(|i| (i, expected[i])).collect::<Vec<_>>());
+        assert_eq!(recorder.step(), 35);
+    }
+
+    #[test]
+    pub fn test_bisect() {
+        let expected = sequence(27);
+        let first_big = expected.iter().position(|v| *v > 9000).unwrap();
+        let mut recorder = Recorder::new(collatz(27), 10);
+        recorder.run(None).unwrap();
+        let found = recorder.bisect(|s| s.peak > 9000).unwrap();
+        assert_eq!(found, Some(first_big));
+        assert_eq!(recorder.simulation().value, expected[first_big]);
+        assert_eq!(recorder.bisect(|s| s.value == 0).unwrap(), None);
+        assert_eq!(recorder.bisect(|_| true).unwrap(), Some(0));
+    }
+
+    #[test]
+    pub fn test_determinism_check() {
+        // a simulation that comes out differently each time it passes through step 5
+        struct Flaky {
+            step: usize,
+            runs: usize,
+        }
+        impl Simulation for Flaky {
+            type Snapshot = (usize, usize);
+            type Error = Error;
+            fn step(&mut self) -> Result<(), Error> {
+                self.step += 1;
+                if self.step == 5 {
+                    self.runs += 1;
+                }
+                Ok(())
+            }
+            fn is_finished(&self) -> bool {
+                self.step == 10
+            }
+            fn snapshot(&self) -> (usize, usize) {
+                (self.step, self.runs)
+            }
+            fn restore(&mut self, snapshot: &(usize, usize)) {
+                self.step = snapshot.0;
+            }
+        }
+        let mut recorder = Recorder::new(Flaky { step: 0, runs: 0 }, 5);
+        recorder.run(None).unwrap();
+        // rewinding to 4 replays from step 0, and carrying on goes back through step 5
+        recorder.rewind(4).unwrap();
+        let error = recorder.run(None).unwrap_err();
+        assert!(error.0.contains("step 5 came out differently"));
+    }
+}
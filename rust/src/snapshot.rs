@@ -0,0 +1,225 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+// golden files for rendered states, e.g. a map after every move, so a bug shows up at the step it happens
+// run with UPDATE_SNAPSHOTS=1 to accept whatever the code does now
+
+const FRAME_PREFIX: &str = "=== ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Frame {
+    label: String,
+    body: String,
+}
+
+pub struct Snapshot {
+    path: PathBuf,
+    frames: Vec<Frame>,
+    update: bool,
+}
+
+impl Snapshot {
+    // stored as snapshots/<name>.txt
+    pub fn new(name: &str) -> Self {
+        Self::in_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots"), name)
+    }
+
+    pub fn in_dir(dir: &Path, name: &str) -> Self {
+        Self {
+            path: dir.join(format!("{name}.txt")),
+            frames: Vec::new(),
+            update: std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| !v.is_empty() && v != "0"),
+        }
+    }
+
+    pub fn record(&mut self, label: impl Display, state: impl Display) {
+        let mut body = state.to_string();
+        if !body.ends_with('\n') {
+            body.push('\n');
+        }
+        self.frames.push(Frame {
+            label: label.to_string(),
+            body,
+        });
+    }
+
+    // panics with a readable diff of the first frame that changed
+    pub fn check(&self) {
+        if let Err(message) = self.compare() {
+            panic!("{message}");
+        }
+    }
+
+    fn compare(&self) -> Result<(), String> {
+        if self.update {
+            std::fs::create_dir_all(self.path.parent().unwrap()).map_err(|e| e.to_string())?;
+            std::fs::write(&self.path, format_frames(&self.frames)).map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+        let expected = match std::fs::read_to_string(&self.path) {
+            Ok(s) => parse_frames(&s),
+            Err(_) => Err(format!(
+                "no snapshot at {}, run with UPDATE_SNAPSHOTS=1 to create it",
+                self.path.display()
+            ))?,
+        };
+
+        let differing = (0..expected.len().max(self.frames.len()))
+            .filter(|i| expected.get(*i) != self.frames.get(*i))
+            .collect::<Vec<_>>();
+        let Some(first) = differing.first() else {
+            return Ok(());
+        };
+        let mut message = format!(
+            "snapshot {} differs in {} of {} frames, run with UPDATE_SNAPSHOTS=1 to accept\n",
+            self.path.display(),
+            differing.len(),
+            expected.len().max(self.frames.len())
+        );
+        match (expected.get(*first), self.frames.get(*first)) {
+            (Some(e), Some(a)) => {
+                message.push_str(&format!("first difference is frame {first}: {}\n", a.label));
+                if e.label != a.label {
+                    message.push_str(&format!("label was: {}\n", e.label));
+                }
+                if let Some(diff) = diff_2d(&e.body, &a.body) {
+                    message.push_str(&diff);
+                }
+            }
+            (Some(e), None) => message.push_str(&format!("frame {first} ({}) and later are missing\n", e.label)),
+            (None, Some(a)) => message.push_str(&format!("frame {first} ({}) and later are new\n", a.label)),
+            (None, None) => unreachable!(),
+        };
+        Err(message)
+    }
+}
+
+fn format_frames(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .map(|frame| format!("{FRAME_PREFIX}{}\n{}", frame.label, frame.body))
+        .collect()
+}
+
+fn parse_frames(s: &str) -> Vec<Frame> {
+    let mut result: Vec<Frame> = Vec::new();
+    for line in s.split_inclusive('\n') {
+        match (line.strip_prefix(FRAME_PREFIX), result.last_mut()) {
+            (Some(label), _) => result.push(Frame {
+                label: label.trim_end_matches('\n').to_string(),
+                body: String::new(),
+            }),
+            (None, Some(frame)) => frame.body.push_str(line),
+            (None, None) => {}
+        };
+    }
+    result
+}
+
+// expected, actual, and a row of * under each cell that changed, side by side; None if they're the same
+pub fn diff_2d(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let grid = |s: &str| s.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let expected = grid(expected);
+    let actual = grid(actual);
+    let size = |g: &Vec<Vec<char>>| (g.iter().map(|row| row.len()).max().unwrap_or(0), g.len());
+    let (expected_width, expected_height) = size(&expected);
+    let (actual_width, actual_height) = size(&actual);
+    let width = expected_width.max(actual_width).max("expected".len());
+    let height = expected_height.max(actual_height);
+
+    let mut result = String::new();
+    if (expected_width, expected_height) != (actual_width, actual_height) {
+        result.push_str(&format!(
+            "size changed from {expected_width}x{expected_height} to {actual_width}x{actual_height}\n"
+        ));
+    }
+    result.push_str(&format!("  {:width$}   {:width$}   changed\n", "expected", "actual"));
+    let mut changed = 0;
+    for y in 0..height {
+        let cell = |g: &Vec<Vec<char>>, x: usize| g.get(y).and_then(|row| row.get(x)).copied();
+        let row = |g: &Vec<Vec<char>>| g.get(y).map(|row| row.iter().collect::<String>()).unwrap_or_default();
+        let marks = (0..width)
+            .map(|x| if cell(&expected, x) != cell(&actual, x) { '*' } else { ' ' })
+            .collect::<String>();
+        let row_changed = marks.chars().filter(|c| *c == '*').count();
+        changed += row_changed;
+        let line = format!(
+            "{} {:width$}   {:width$}   {}",
+            if row_changed > 0 { '>' } else { ' ' },
+            row(&expected),
+            row(&actual),
+            marks
+        );
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result.push_str(&format!("{changed} cells changed\n"));
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-snapshot-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    pub fn test_diff_2d() {
+        assert_eq!(diff_2d("#..\n.@.\n", "#..\n.@.\n"), None);
+        assert_eq!(
+            diff_2d("#[]..\n.@...\n", "#.[].\n.@...\n").unwrap(),
+            "  expected   actual     changed\n\
+             > #[]..      #.[].       ***\n  \
+             .@...      .@...\n\
+             3 cells changed\n"
+        );
+        let diff = diff_2d("ab\n", "ab\ncd\n").unwrap();
+        assert!(diff.starts_with("size changed from 2x1 to 2x2\n"));
+        assert!(diff.ends_with("2 cells changed\n"));
+    }
+
+    #[test]
+    pub fn test_frames_round_trip() {
+        let frames = vec![
+            Frame {
+                label: "start".to_string(),
+                body: "#.\n.#\n".to_string(),
+            },
+            Frame {
+                label: "move 1 (<)".to_string(),
+                body: "\n=.\n".to_string(),
+            },
+        ];
+        assert_eq!(parse_frames(&format_frames(&frames)), frames);
+    }
+
+    #[test]
+    pub fn test_check() {
+        let dir = temp_dir("check");
+        let record = |update: bool, second: &str| {
+            let mut snapshot = Snapshot::in_dir(&dir, "grid");
+            snapshot.update = update;
+            snapshot.record("start", "#..\n.@.\n");
+            snapshot.record(1, second);
+            snapshot
+        };
+        assert!(record(false, "#..\n..@\n").compare().unwrap_err().contains("UPDATE_SNAPSHOTS=1 to create"));
+        assert!(record(true, "#..\n..@\n").compare().is_ok());
+        assert!(record(false, "#..\n..@\n").compare().is_ok());
+
+        let message = record(false, "#..\n.@.\n").compare().unwrap_err();
+        assert!(message.contains("differs in 1 of 2 frames"));
+        assert!(message.contains("first difference is frame 1: 1\n"));
+        assert!(message.contains("2 cells changed"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
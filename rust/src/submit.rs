@@ -0,0 +1,401 @@
+use std::{
+    fmt::Display,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answer::Answer,
+    fetch::{self, Client},
+    runner::{self, Part},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<fetch::Error> for Error {
+    fn from(value: fetch::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // how long the server says to wait, if it said
+    RateLimited(Option<Duration>),
+    // the server wasn't expecting an answer for this part, which it says both when the part is already solved and
+    // when part 2 is sent before part 1 is solved, so it doesn't say anything about the answer
+    WrongLevel,
+}
+
+impl Outcome {
+    // the name used in the history file
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited(_) => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "rate-limited" => Some(Outcome::RateLimited(None)),
+            // what wrong-level used to be called
+            "wrong-level" | "already-solved" => Some(Outcome::WrongLevel),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(Some(wait)) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Outcome::RateLimited(None) => write!(f, "rate limited"),
+            Outcome::WrongLevel => write!(f, "wrong level, either already solved or the part before isn't solved yet"),
+        }
+    }
+}
+
+// turns the html page the server sends back into an outcome
+pub fn parse_response(html: &str) -> Result<Outcome> {
+    if html.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if html.contains("You gave an answer too recently") {
+        Ok(Outcome::RateLimited(parse_wait(html)))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::WrongLevel)
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else {
+        Err("unrecognized response from server")?
+    }
+}
+
+// e.g. "You have 4m 3s left to wait." or "You have 35s left to wait."
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for word in html[start..end].split_whitespace() {
+        if let Some(m) = word.strip_suffix('m') {
+            seconds += m.parse::<u64>().ok()? * 60;
+        } else if let Some(s) = word.strip_suffix('s') {
+            seconds += s.parse::<u64>().ok()?;
+        } else {
+            return None;
+        }
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+// every answer submitted so far, one tab separated line each, e.g. "21\ta\t217676\ttoo-high"
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn default_path(year: u32) -> PathBuf {
+        runner::puzzle_inputs_dir(year).join("submissions.tsv")
+    }
+
+    // a missing file is just an empty history
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut entries = Vec::new();
+        if path.exists() {
+            for (i, line) in std::fs::read_to_string(path)?.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let fields = line.split('\t').collect::<Vec<_>>();
+                let entry = match fields.as_slice() {
+                    [day, part, answer, outcome] => Entry {
+                        day: day.parse().map_err(|_| format!("{}:{}: invalid day: {day}", path.display(), i + 1))?,
+                        part: Part::try_from(*part).map_err(|e| format!("{}:{}: {}", path.display(), i + 1, e.0))?,
+                        answer: answer.to_string(),
+                        outcome: Outcome::from_name(outcome)
+                            .ok_or(format!("{}:{}: invalid outcome: {outcome}", path.display(), i + 1))?,
+                    },
+                    _ => Err(format!("{}:{}: expected 4 tab separated fields", path.display(), i + 1))?,
+                };
+                entries.push(entry);
+            }
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, entry: Entry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            entry.day,
+            entry.part,
+            entry.answer,
+            entry.outcome.name()
+        )?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    // why this answer shouldn't be sent, if there's a reason
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Option<String> {
+        // compared as answers, so 0042 counts as a repeat of 42
+        let Ok(parsed) = answer.parse::<Answer>() else {
+            return Some(format!("{answer:?} isn't an answer"));
+        };
+        let number = parsed.as_i128();
+        let mut highest_too_low = None;
+        let mut lowest_too_high = None;
+        for entry in self.entries.iter().filter(|e| e.day == day && e.part == part) {
+            match entry.outcome {
+                Outcome::Correct => {
+                    return Some(format!("day {day} part {part} is already solved"));
+                }
+                // neither says anything about the answer, so it can be sent again
+                Outcome::RateLimited(_) | Outcome::WrongLevel => continue,
+                Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong if parsed == entry.answer.as_str() => {
+                    return Some(format!("{answer} was already submitted and was {}", entry.outcome));
+                }
+                Outcome::TooHigh => {
+                    if let Some(bound) = entry.answer.parse::<Answer>().ok().and_then(|a| a.as_i128()) {
+                        lowest_too_high = Some(lowest_too_high.map_or(bound, |b: i128| b.min(bound)));
+                    }
+                }
+                Outcome::TooLow => {
+                    if let Some(bound) = entry.answer.parse::<Answer>().ok().and_then(|a| a.as_i128()) {
+                        highest_too_low = Some(highest_too_low.map_or(bound, |b: i128| b.max(bound)));
+                    }
+                }
+                Outcome::Wrong => {}
+            };
+        }
+        match (number, highest_too_low, lowest_too_high) {
+            (Some(n), _, Some(high)) if n >= high => Some(format!("{answer} is too high, {high} already was")),
+            (Some(n), Some(low), _) if n <= low => Some(format!("{answer} is too low, {low} already was")),
+            _ => None,
+        }
+    }
+}
+
+// sends an answer unless the history already rules it out, and records what the server said
+pub fn submit(client: &mut Client, history: &mut History, day: u32, part: Part, answer: &str) -> Result<Outcome> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        Err("answer is empty")?;
+    }
+    if let Some(reason) = history.check(day, part, answer) {
+        Err(format!("refusing to submit: {reason}"))?;
+    }
+
+    let level = match part {
+        Part::A => "1",
+        Part::B => "2",
+    };
+    let (status, body) = client.post_form(&format!("/day/{day}/answer"), &[("level", level), ("answer", answer)])?;
+    if status != 200 {
+        Err(format!("day {day}: unexpected status {status}: {}", body.trim()))?;
+    }
+    let outcome = parse_response(&body)?;
+    history.record(Entry {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+    })?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    pub fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are one gold star closer to finding the Chief Historian.")).unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, ...")).unwrap(),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too low.  If you're stuck, ...")).unwrap(),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, ...")).unwrap(),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 3s left to wait.")).unwrap(),
+            Outcome::RateLimited(Some(Duration::from_secs(243)))
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 35s left to wait.")).unwrap(),
+            Outcome::RateLimited(Some(Duration::from_secs(35)))
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")).unwrap(),
+            Outcome::WrongLevel
+        );
+        assert!(parse_response(&page("something else")).is_err());
+    }
+
+    #[test]
+    pub fn test_history() {
+        let dir = temp_dir("history");
+        let path = dir.join("submissions.tsv");
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(history.check(21, Part::A, "217676"), None);
+        for (answer, outcome) in [
+            ("217676", Outcome::TooHigh),
+            ("200000", Outcome::TooHigh),
+            ("100", Outcome::TooLow),
+            ("12345", Outcome::Wrong),
+            ("54321", Outcome::RateLimited(None)),
+            ("11111", Outcome::WrongLevel),
+        ] {
+            history
+                .record(Entry {
+                    day: 21,
+                    part: Part::A,
+                    answer: answer.to_string(),
+                    outcome,
+                })
+                .unwrap();
+        }
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.entries().len(), 6);
+        assert!(history.check(21, Part::A, "217676").is_some());
+        assert!(history.check(21, Part::A, "0217676").is_some());
+        assert!(history.check(21, Part::A, "200001").is_some());
+        assert!(history.check(21, Part::A, "200000").is_some());
+        assert!(history.check(21, Part::A, "100").is_some());
+        assert!(history.check(21, Part::A, "50").is_some());
+        assert!(history.check(21, Part::A, "12345").is_some());
+        assert_eq!(history.check(21, Part::A, "54321"), None);
+        assert_eq!(history.check(21, Part::A, "11111"), None);
+        assert!(history.check(21, Part::A, " ").is_some());
+        assert_eq!(history.check(21, Part::A, "199999"), None);
+        assert_eq!(history.check(21, Part::B, "217676"), None);
+        assert_eq!(history.check(22, Part::A, "217676"), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    pub fn test_submit() {
+        let server = MockServer::start(|request| match (request.path.as_str(), request.body.as_str()) {
+            ("/2024/day/22/answer", "level=2&answer=2002") => (
+                200,
+                page("That's not the right answer; your answer is too high.  If you're stuck, ..."),
+            ),
+            ("/2024/day/22/answer", "level=2&answer=1998") => (200, page("That's the right answer!")),
+            _ => (404, "not found".to_string()),
+        });
+        let dir = temp_dir("submit");
+        let mut client = Client::new(fetch::Config {
+            base_url: server.url.clone(),
+            session: "abc123".to_string(),
+            year: 2024,
+            inputs_dir: dir.clone(),
+            min_interval: Duration::from_millis(0),
+        });
+        let mut history = History::load(&dir.join("submissions.tsv")).unwrap();
+
+        assert_eq!(submit(&mut client, &mut history, 22, Part::B, "2002").unwrap(), Outcome::TooHigh);
+        // refused without asking the server
+        assert!(submit(&mut client, &mut history, 22, Part::B, "2002").is_err());
+        assert!(submit(&mut client, &mut history, 22, Part::B, "3000").is_err());
+        assert_eq!(server.requests().len(), 1);
+
+        assert_eq!(submit(&mut client, &mut history, 22, Part::B, "1998").unwrap(), Outcome::Correct);
+        assert!(submit(&mut client, &mut history, 22, Part::B, "1999").is_err());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("submissions.tsv")).unwrap(),
+            "22\tb\t2002\ttoo-high\n22\tb\t1998\tcorrect\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
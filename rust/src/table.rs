@@ -0,0 +1,225 @@
+use std::{fmt::Display, path::Path, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Separator {
+    // any run of spaces and tabs, like day 1's two columns
+    Whitespace,
+    // exactly this, with the spaces around each value trimmed, e.g. "," or " | "
+    Text(String),
+}
+
+// reads rows of numbers, one row per line, skipping blank lines
+// errors say where in the input the problem is, as source:line:column, counting both from 1
+#[derive(Debug, Clone)]
+pub struct Reader {
+    pub separator: Separator,
+    // how many values every row has to have, or None to let rows be different lengths
+    pub columns: Option<usize>,
+}
+
+impl Default for Reader {
+    fn default() -> Self {
+        Self {
+            separator: Separator::Whitespace,
+            columns: None,
+        }
+    }
+}
+
+impl Reader {
+    pub fn with_columns(columns: usize) -> Self {
+        Self {
+            columns: Some(columns),
+            ..Self::default()
+        }
+    }
+
+    pub fn separated_by(separator: &str) -> Self {
+        Self {
+            separator: Separator::Text(separator.to_string()),
+            ..Self::default()
+        }
+    }
+
+    // each value along with the column it starts at
+    fn split<'a>(&self, line: &'a str) -> Vec<(usize, &'a str)> {
+        let column = |offset: usize| line[..offset].chars().count() + 1;
+        match &self.separator {
+            Separator::Whitespace => {
+                let mut result = Vec::new();
+                let mut start = None;
+                for (i, c) in line.char_indices().chain([(line.len(), ' ')]) {
+                    match (c.is_whitespace(), start) {
+                        (true, Some(s)) => {
+                            result.push((column(s), &line[s..i]));
+                            start = None;
+                        }
+                        (false, None) => start = Some(i),
+                        _ => {}
+                    };
+                }
+                result
+            }
+            Separator::Text(separator) => {
+                let mut result = Vec::new();
+                let mut start = 0;
+                for end in line.match_indices(separator.as_str()).map(|(i, _)| i).chain([line.len()]) {
+                    let field = &line[start..end];
+                    let trimmed = field.trim_start();
+                    result.push((column(start + field.len() - trimmed.len()), trimmed.trim_end()));
+                    start = end + separator.len();
+                }
+                result
+            }
+        }
+    }
+
+    // one Vec per line, in order
+    pub fn parse_rows<T>(&self, s: &str, source: &str) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut result = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let values = self.split(line);
+            if let Some(columns) = self.columns {
+                if values.len() != columns {
+                    // point at the first value too many, or just past the end if there are too few
+                    let column = values.get(columns).map_or(line.trim_end().chars().count() + 1, |(c, _)| *c);
+                    Err(format!(
+                        "{source}:{}:{column}: expected {columns} values, got {}",
+                        i + 1,
+                        values.len()
+                    ))?;
+                }
+            }
+            let row = values
+                .into_iter()
+                .map(|(column, value)| {
+                    value.parse::<T>().map_err(|e| {
+                        Error(format!(
+                            "{source}:{}:{column}: expected {}, got {value:?} ({e})",
+                            i + 1,
+                            std::any::type_name::<T>()
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            result.push(row);
+        }
+        Ok(result)
+    }
+
+    // one Vec per column, which needs every row to be the same length
+    // with columns set there are always that many, even for an empty input
+    pub fn parse_columns<T>(&self, s: &str, source: &str) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let rows = self.parse_rows::<T>(s, source)?;
+        let width = self.columns.or(rows.first().map(|row| row.len())).unwrap_or(0);
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            // blank lines are skipped, so the row number isn't the line number
+            Err(format!(
+                "{source}: expected every row to have {width} values like the first, row {} has {}",
+                i + 1,
+                row.len()
+            ))?;
+        }
+        let mut result = (0..width).map(|_| Vec::with_capacity(rows.len())).collect::<Vec<_>>();
+        for row in rows {
+            for (column, value) in result.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn read_rows<T>(&self, path: &Path) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_rows(&std::fs::read_to_string(path)?, &source(path))
+    }
+
+    pub fn read_columns<T>(&self, path: &Path) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_columns(&std::fs::read_to_string(path)?, &source(path))
+    }
+}
+
+// inputs all live in the same directory, so the file name is enough to say which one
+fn source(path: &Path) -> String {
+    path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rows() {
+        let rows = Reader::default().parse_rows::<i32>("7 6  4\n\n1\t-2\n", "x").unwrap();
+        assert_eq!(rows, vec![vec![7, 6, 4], vec![1, -2]]);
+        let rows = Reader::separated_by(",").parse_rows::<u8>(" 75, 47 ,61\n97,13\n", "x").unwrap();
+        assert_eq!(rows, vec![vec![75, 47, 61], vec![97, 13]]);
+        let rows = Reader::separated_by(" | ").parse_rows::<u64>("47 | 53\n", "x").unwrap();
+        assert_eq!(rows, vec![vec![47, 53]]);
+    }
+
+    #[test]
+    pub fn test_columns() {
+        let columns = Reader::with_columns(2).parse_columns::<u32>("3   4\n4   3\n2   5\n", "x").unwrap();
+        assert_eq!(columns, vec![vec![3, 4, 2], vec![4, 3, 5]]);
+        assert_eq!(Reader::with_columns(1).parse_columns::<u64>("", "x").unwrap(), vec![Vec::<u64>::new()]);
+        assert!(Reader::default().parse_columns::<u64>("", "x").unwrap().is_empty());
+        let error = Reader::default().parse_columns::<u64>("1 2\n3\n", "x").unwrap_err();
+        assert_eq!(error.0, "x: expected every row to have 2 values like the first, row 2 has 1");
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let error = Reader::default().parse_rows::<u32>("1 2\n\n3  -4\n", "day01.txt").unwrap_err();
+        assert_eq!(error.0, "day01.txt:3:4: expected u32, got \"-4\" (invalid digit found in string)");
+        let error = Reader::separated_by(",").parse_rows::<i8>("1, 200\n", "x").unwrap_err();
+        assert_eq!(error.0, "x:1:4: expected i8, got \"200\" (number too large to fit in target type)");
+        let error = Reader::with_columns(2).parse_rows::<u32>("1 2 3\n", "x").unwrap_err();
+        assert_eq!(error.0, "x:1:5: expected 2 values, got 3");
+        let error = Reader::with_columns(2).parse_rows::<u32>("1 2\n1 \n", "x").unwrap_err();
+        assert_eq!(error.0, "x:2:2: expected 2 values, got 1");
+        let error = Reader::separated_by(",").parse_rows::<u32>("1,,2\n", "x").unwrap_err();
+        assert_eq!(error.0, "x:1:3: expected u32, got \"\" (cannot parse integer from empty string)");
+    }
+}
@@ -0,0 +1,415 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answer::{self, Answer},
+    runner::{self, Outcome, Part},
+};
+
+#[derive(Debug, Clone)]
+pub struct Error(pub String);
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<runner::Error> for Error {
+    fn from(value: runner::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// an answer checked against the puzzle site, or the one the puzzle text gives for a sample
+pub struct Known {
+    // as runner::find_by_name takes it
+    pub solver: &'static str,
+    // relative to the year's puzzle inputs directory
+    pub input: &'static str,
+    pub params: &'static [(&'static str, u64)],
+    pub answer: &'static str,
+}
+
+// the same answers the tests for each day check, leaving out the placeholders' real inputs
+#[rustfmt::skip]
+pub static KNOWN: &[Known] = &[
+    Known { solver: "day01a", input: "day01-sample.txt", params: &[], answer: "11" },
+    Known { solver: "day01a", input: "day01.txt", params: &[], answer: "1319616" },
+    Known { solver: "day01b", input: "day01-sample.txt", params: &[], answer: "31" },
+    Known { solver: "day01b", input: "day01.txt", params: &[], answer: "27267728" },
+    Known { solver: "day02a", input: "day02-sample.txt", params: &[], answer: "2" },
+    Known { solver: "day02a", input: "day02.txt", params: &[], answer: "572" },
+    Known { solver: "day02b", input: "day02-sample.txt", params: &[], answer: "4" },
+    Known { solver: "day02b", input: "day02.txt", params: &[], answer: "612" },
+    Known { solver: "day03a", input: "day03a-sample.txt", params: &[], answer: "161" },
+    Known { solver: "day03a", input: "day03.txt", params: &[], answer: "192767529" },
+    Known { solver: "day03b", input: "day03b-sample.txt", params: &[], answer: "48" },
+    Known { solver: "day03b", input: "day03.txt", params: &[], answer: "104083373" },
+    Known { solver: "day04a", input: "day04a-sample1.txt", params: &[], answer: "4" },
+    Known { solver: "day04a", input: "day04a-sample2.txt", params: &[], answer: "18" },
+    Known { solver: "day04a", input: "day04.txt", params: &[], answer: "2543" },
+    Known { solver: "day04b", input: "day04b-sample1.txt", params: &[], answer: "1" },
+    Known { solver: "day04b", input: "day04b-sample2.txt", params: &[], answer: "9" },
+    Known { solver: "day04b", input: "day04.txt", params: &[], answer: "1930" },
+    Known { solver: "day05a", input: "day05-sample.txt", params: &[], answer: "143" },
+    Known { solver: "day05a", input: "day05.txt", params: &[], answer: "5391" },
+    Known { solver: "day05b", input: "day05-sample.txt", params: &[], answer: "123" },
+    Known { solver: "day05b", input: "day05.txt", params: &[], answer: "6142" },
+    Known { solver: "day06a", input: "day06-sample.txt", params: &[], answer: "41" },
+    Known { solver: "day06a", input: "day06.txt", params: &[], answer: "5208" },
+    Known { solver: "day06b", input: "day06-sample.txt", params: &[], answer: "6" },
+    Known { solver: "day06b", input: "day06.txt", params: &[], answer: "1972" },
+    Known { solver: "day07a", input: "day07-sample.txt", params: &[], answer: "3749" },
+    Known { solver: "day07a", input: "day07.txt", params: &[], answer: "1620690235709" },
+    Known { solver: "day07b", input: "day07-sample.txt", params: &[], answer: "11387" },
+    Known { solver: "day07b", input: "day07.txt", params: &[], answer: "145397611075341" },
+    Known { solver: "day08a", input: "day08-sample.txt", params: &[], answer: "14" },
+    Known { solver: "day08a", input: "day08.txt", params: &[], answer: "220" },
+    Known { solver: "day08b", input: "day08-sample.txt", params: &[], answer: "34" },
+    Known { solver: "day08b", input: "day08.txt", params: &[], answer: "813" },
+    Known { solver: "day09a", input: "day09-sample.txt", params: &[], answer: "1928" },
+    Known { solver: "day09a", input: "day09.txt", params: &[], answer: "6398252054886" },
+    Known { solver: "day09b", input: "day09-sample.txt", params: &[], answer: "2858" },
+    Known { solver: "day09b", input: "day09.txt", params: &[], answer: "6415666220005" },
+    Known { solver: "day10a", input: "day10-sample1.txt", params: &[], answer: "1" },
+    Known { solver: "day10a", input: "day10-sample2.txt", params: &[], answer: "36" },
+    Known { solver: "day10a", input: "day10.txt", params: &[], answer: "674" },
+    Known { solver: "day10b", input: "day10-sample2.txt", params: &[], answer: "81" },
+    Known { solver: "day10b", input: "day10.txt", params: &[], answer: "1372" },
+    Known { solver: "day11a", input: "day11-sample.txt", params: &[], answer: "55312" },
+    Known { solver: "day11a", input: "day11.txt", params: &[], answer: "186175" },
+    Known { solver: "day11b", input: "day11.txt", params: &[], answer: "220566831337810" },
+    Known { solver: "day12a", input: "day12-sample1.txt", params: &[], answer: "140" },
+    Known { solver: "day12a", input: "day12-sample2.txt", params: &[], answer: "772" },
+    Known { solver: "day12a", input: "day12-sample3.txt", params: &[], answer: "1930" },
+    Known { solver: "day12a", input: "day12.txt", params: &[], answer: "1433460" },
+    Known { solver: "day12b", input: "day12-sample1.txt", params: &[], answer: "80" },
+    Known { solver: "day12b", input: "day12b-sample2.txt", params: &[], answer: "236" },
+    Known { solver: "day12b", input: "day12b-sample3.txt", params: &[], answer: "368" },
+    Known { solver: "day12b", input: "day12-sample3.txt", params: &[], answer: "1206" },
+    Known { solver: "day12b", input: "day12.txt", params: &[], answer: "855082" },
+    Known { solver: "day13a", input: "day13-sample.txt", params: &[], answer: "480" },
+    Known { solver: "day13a", input: "day13.txt", params: &[], answer: "39748" },
+    Known { solver: "day13b", input: "day13.txt", params: &[], answer: "74478585072604" },
+    Known { solver: "day14a", input: "day14-sample.txt", params: &[("width", 11), ("height", 7)], answer: "12" },
+    Known { solver: "day14a", input: "day14.txt", params: &[], answer: "217328832" },
+    Known { solver: "day14b", input: "day14.txt", params: &[], answer: "7412" },
+    Known { solver: "day15a", input: "day15-sample1.txt", params: &[], answer: "2028" },
+    Known { solver: "day15a", input: "day15-sample2.txt", params: &[], answer: "10092" },
+    Known { solver: "day15a", input: "day15.txt", params: &[], answer: "1517819" },
+    Known { solver: "day15b", input: "day15b-sample1.txt", params: &[], answer: "618" },
+    Known { solver: "day15b", input: "day15-sample2.txt", params: &[], answer: "9021" },
+    Known { solver: "day15b", input: "day15.txt", params: &[], answer: "1538862" },
+    Known { solver: "day16a", input: "day16-sample1.txt", params: &[], answer: "7036" },
+    Known { solver: "day16a", input: "day16-sample2.txt", params: &[], answer: "11048" },
+    Known { solver: "day16a", input: "day16.txt", params: &[], answer: "75416" },
+    Known { solver: "day16b", input: "day16-sample1.txt", params: &[], answer: "45" },
+    Known { solver: "day16b", input: "day16-sample2.txt", params: &[], answer: "64" },
+    Known { solver: "day16b", input: "day16.txt", params: &[], answer: "476" },
+    Known { solver: "day17a", input: "day17-sample.txt", params: &[], answer: "4,6,3,5,6,3,5,2,1,0" },
+    Known { solver: "day17a", input: "day17.txt", params: &[], answer: "1,6,3,6,5,6,5,1,7" },
+    Known { solver: "day17b", input: "day17b-sample.txt", params: &[], answer: "117440" },
+    Known { solver: "day18a", input: "day18-sample.txt", params: &[("width", 7), ("height", 7), ("count", 12)], answer: "22" },
+    Known { solver: "day18a", input: "day18.txt", params: &[], answer: "278" },
+    Known { solver: "day18b", input: "day18-sample.txt", params: &[("width", 7), ("height", 7)], answer: "6,1" },
+    Known { solver: "day18b", input: "day18.txt", params: &[], answer: "43,12" },
+    Known { solver: "day19a", input: "day19-sample.txt", params: &[], answer: "6" },
+    Known { solver: "day19a", input: "day19.txt", params: &[], answer: "298" },
+    Known { solver: "day19b", input: "day19-sample.txt", params: &[], answer: "16" },
+    Known { solver: "day19b", input: "day19.txt", params: &[], answer: "572248688842069" },
+    Known { solver: "day20a", input: "day20-sample.txt", params: &[("at_least_time_saved", 20)], answer: "5" },
+    Known { solver: "day20a", input: "day20.txt", params: &[], answer: "1375" },
+    Known { solver: "day20b", input: "day20-sample.txt", params: &[("at_least_time_saved", 70)], answer: "41" },
+    Known { solver: "day21a", input: "day21-sample.txt", params: &[], answer: "126384" },
+    Known { solver: "day22a", input: "day22-sample.txt", params: &[], answer: "37327623" },
+    Known { solver: "day22a", input: "day22.txt", params: &[], answer: "17612566393" },
+    Known { solver: "day22b", input: "day22b-sample.txt", params: &[], answer: "23" },
+    Known { solver: "day23a", input: "day23-sample.txt", params: &[], answer: "7" },
+    Known { solver: "day23a", input: "day23.txt", params: &[], answer: "1306" },
+    Known { solver: "day23b", input: "day23-sample.txt", params: &[], answer: "co,de,ka,ta" },
+    Known { solver: "day23b", input: "day23.txt", params: &[], answer: "bd,dk,ir,ko,lk,nn,ob,pt,te,tl,uh,wj,yl" },
+    Known { solver: "day24a", input: "day24-sample1.txt", params: &[], answer: "4" },
+    Known { solver: "day24a", input: "day24-sample2.txt", params: &[], answer: "2024" },
+    Known { solver: "day24a", input: "day24.txt", params: &[], answer: "51410244478064" },
+    Known { solver: "day25a", input: "day25-sample.txt", params: &[], answer: "3" },
+    Known { solver: "day25a", input: "day25.txt", params: &[], answer: "3127" },
+];
+
+// one input, what to run on it, and the answer any implementation should get
+// params are every parameter the solver takes, defaults included, so a port doesn't need to know the defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vector {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub input: String,
+    pub params: Vec<(String, u64)>,
+    pub answer: Answer,
+}
+
+impl Vector {
+    // what identifies a vector, so results written back can be matched up with it
+    fn key(&self) -> (u32, u32, Part, &str, &[(String, u64)]) {
+        (self.year, self.day, self.part, &self.input, &self.params)
+    }
+
+    fn name(&self) -> String {
+        let params = self.params.iter().map(|(name, value)| format!(" --{name} {value}")).collect::<String>();
+        format!("{}/day{:02}{} {}{params}", self.year, self.day, self.part, self.input)
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("test-vectors.tsv")
+}
+
+const HEADER: &str = "# year\tday\tpart\tinput\tparams\tanswer\n";
+
+// one tab separated line per vector, e.g. "2024\t14\ta\tday14-sample.txt\twidth=11,height=7\t12", with "-" for
+// no params, and lines starting with # ignored
+pub fn format(vectors: &[Vector]) -> String {
+    let mut result = HEADER.to_string();
+    for v in vectors {
+        let params = match v.params.is_empty() {
+            true => "-".to_string(),
+            false => v.params.iter().map(|(name, value)| format!("{name}={value}")).collect::<Vec<_>>().join(","),
+        };
+        result += &format!("{}\t{}\t{}\t{}\t{params}\t{}\n", v.year, v.day, v.part, v.input, v.answer);
+    }
+    result
+}
+
+pub fn parse(s: &str, source: &str) -> Result<Vec<Vector>> {
+    let mut result = Vec::new();
+    for (i, line) in s.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let at = |message: String| Error(format!("{source}:{}: {message}", i + 1));
+        let [year, day, part, input, params, answer] = line.split('\t').collect::<Vec<_>>()[..] else {
+            return Err(at("expected 6 tab separated fields".to_string()));
+        };
+        let params = match params {
+            "-" => Vec::new(),
+            _ => params
+                .split(',')
+                .map(|param| {
+                    let (name, value) = param.split_once('=').ok_or(at(format!("invalid param: {param}")))?;
+                    let value = value.parse().map_err(|_| at(format!("invalid value for {name}: {value}")))?;
+                    Ok((name.to_string(), value))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        result.push(Vector {
+            year: year.parse().map_err(|_| at(format!("invalid year: {year}")))?,
+            day: day.parse().map_err(|_| at(format!("invalid day: {day}")))?,
+            part: Part::try_from(part).map_err(|e| at(e.0))?,
+            input: input.to_string(),
+            params,
+            answer: answer.parse().map_err(|_| at(format!("invalid answer: {answer}")))?,
+        });
+    }
+    Ok(result)
+}
+
+pub fn read(path: &Path) -> Result<Vec<Vector>> {
+    parse(&std::fs::read_to_string(path)?, &path.display().to_string())
+}
+
+pub fn write(path: &Path, vectors: &[Vector]) -> Result<()> {
+    std::fs::write(path, format(vectors))?;
+    Ok(())
+}
+
+// every entry in KNOWN as a vector, without running anything
+pub fn known() -> Result<Vec<Vector>> {
+    KNOWN
+        .iter()
+        .map(|known| {
+            let solver = runner::find_by_name(known.solver)?;
+            let overrides = known.params.iter().map(|(name, value)| (name.to_string(), *value)).collect::<Vec<_>>();
+            let params = solver.params.iter().map(|p| p.name.to_string()).zip(solver.resolve_params(&overrides)?);
+            Ok(Vector {
+                year: solver.year,
+                day: solver.day,
+                part: solver.part,
+                input: known.input.to_string(),
+                params: params.collect(),
+                answer: known
+                    .answer
+                    .parse()
+                    .map_err(|e: answer::Error| format!("{} {}: {}", known.solver, known.input, e.0))?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub vectors: Vec<Vector>,
+    // known answers the solver doesn't get at the moment, with what it did instead
+    pub skipped: Vec<(Vector, String)>,
+}
+
+// runs every known answer through the Rust solver, keeping only the ones it still gets right
+pub fn export(timeout: Duration) -> Result<Export> {
+    let mut result = Export {
+        vectors: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for vector in known()? {
+        let solver = runner::find_in_year(vector.year, vector.day, vector.part).ok_or("solver went missing")?;
+        let path = runner::puzzle_inputs_dir(vector.year).join(&vector.input);
+        let outcome = runner::solve_with_timeout(solver, &path.to_string_lossy(), &vector.params, timeout);
+        let problem = match outcome {
+            Outcome::Solved { answer, .. } if answer == vector.answer => None,
+            Outcome::Solved { answer, .. } => Some(format!("got {answer}")),
+            Outcome::Failed { error, .. } => Some(format!("failed: {error}")),
+            Outcome::Panicked => Some("panicked".to_string()),
+            Outcome::TimedOut => Some(format!("timed out after {timeout:?}")),
+        };
+        match problem {
+            None => result.vectors.push(vector),
+            Some(problem) => result.skipped.push((vector, problem)),
+        };
+    }
+    Ok(result)
+}
+
+// another implementation's results compared with the vectors it was given
+#[derive(Debug, Clone, Default)]
+pub struct Diff {
+    pub matched: usize,
+    // the vector, and the answer the other implementation gave instead
+    pub mismatched: Vec<(Vector, Answer)>,
+    // vectors there's no result for, which is expected for days a port hasn't got to
+    pub missing: Vec<Vector>,
+    // results for something that isn't a vector
+    pub extra: Vec<Vector>,
+}
+
+impl Diff {
+    pub fn is_clean(&self) -> bool {
+        self.mismatched.is_empty() && self.extra.is_empty()
+    }
+}
+
+pub fn diff(expected: &[Vector], actual: &[Vector]) -> Diff {
+    let mut result = Diff::default();
+    for vector in expected {
+        match actual.iter().find(|a| a.key() == vector.key()) {
+            Some(a) if a.answer == vector.answer => result.matched += 1,
+            Some(a) => result.mismatched.push((vector.clone(), a.answer.clone())),
+            None => result.missing.push(vector.clone()),
+        };
+    }
+    result.extra = actual.iter().filter(|a| !expected.iter().any(|e| e.key() == a.key())).cloned().collect();
+    result
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (vector, answer) in self.mismatched.iter() {
+            writeln!(f, "wrong    {}: expected {}, got {answer}", vector.name(), vector.answer)?;
+        }
+        for vector in self.extra.iter() {
+            writeln!(f, "extra    {}: not a test vector", vector.name())?;
+        }
+        for vector in self.missing.iter() {
+            writeln!(f, "missing  {}", vector.name())?;
+        }
+        writeln!(
+            f,
+            "{} matched, {} wrong, {} extra, {} missing",
+            self.matched,
+            self.mismatched.len(),
+            self.extra.len(),
+            self.missing.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_known() {
+        let vectors = known().unwrap();
+        assert_eq!(vectors.len(), KNOWN.len());
+        let day14 = vectors.iter().find(|v| v.day == 14 && v.input == "day14.txt").unwrap();
+        assert_eq!(day14.params, vec![("width".to_string(), 101), ("height".to_string(), 103)]);
+        for solver in runner::SOLVERS.iter().filter(|s| !s.is_placeholder()) {
+            let real = solver.input();
+            assert!(
+                KNOWN.iter().any(|k| k.solver == solver.name() && k.input == real),
+                "no known answer for {} on the real input",
+                solver.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_round_trip() {
+        let vectors = known().unwrap();
+        let formatted = format(&vectors);
+        assert!(formatted.contains("\n2024\t14\ta\tday14-sample.txt\twidth=11,height=7\t12\n"));
+        assert!(formatted.contains("\n2024\t1\ta\tday01.txt\t-\t1319616\n"));
+        assert_eq!(parse(&formatted, "vectors").unwrap(), vectors);
+        let error = parse("2024\t1\ta\tday01.txt\t-\n", "results.tsv").unwrap_err();
+        assert_eq!(error.0, "results.tsv:1: expected 6 tab separated fields");
+        assert!(parse("2024\t1\tc\tday01.txt\t-\t11\n", "x").is_err());
+        assert!(parse("2024\t1\ta\tday01.txt\twidth\t11\n", "x").is_err());
+    }
+
+    #[test]
+    pub fn test_checked_in_vectors_are_current() {
+        // regenerate with aoc vectors export if this fails
+        assert_eq!(read(&default_path()).unwrap(), known().unwrap());
+    }
+
+    #[test]
+    pub fn test_diff() {
+        let expected = parse(
+            "2024\t1\ta\tday01-sample.txt\t-\t11\n\
+             2024\t1\ta\tday01.txt\t-\t1319616\n\
+             2024\t14\ta\tday14-sample.txt\twidth=11,height=7\t12\n",
+            "expected",
+        )
+        .unwrap();
+        let actual = parse(
+            "2024\t1\ta\tday01.txt\t-\t01319616\n\
+             2024\t14\ta\tday14-sample.txt\twidth=11,height=7\t13\n\
+             2024\t14\ta\tday14-sample.txt\twidth=11,height=8\t12\n",
+            "actual",
+        )
+        .unwrap();
+        let diff = diff(&expected, &actual);
+        assert_eq!(diff.matched, 1);
+        assert_eq!(diff.mismatched.len(), 1);
+        assert_eq!(diff.missing[0].input, "day01-sample.txt");
+        assert_eq!(diff.extra.len(), 1);
+        assert!(!diff.is_clean());
+        assert!(diff
+            .to_string()
+            .contains("wrong    2024/day14a day14-sample.txt --width 11 --height 7: expected 12, got 13"));
+    }
+}
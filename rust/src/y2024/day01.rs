@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, env, fmt::Display, iter::zip, path::Path};
+
+use crate::table::{self, Reader};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(pub(crate) String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// the two location id lists, in the order they were given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Lists {
+    pub(crate) left: Vec<u32>,
+    pub(crate) right: Vec<u32>,
+}
+
+// the nth smallest id from each list, which part 1 pairs up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Pair {
+    // 0 for the smallest
+    pub(crate) rank: usize,
+    pub(crate) left: u32,
+    pub(crate) right: u32,
+    pub(crate) distance: u32,
+}
+
+// an id in both lists, and how much it adds to the similarity score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Shared {
+    pub(crate) id: u32,
+    pub(crate) left_count: usize,
+    pub(crate) right_count: usize,
+    pub(crate) similarity: u64,
+}
+
+// what's left of each list after taking away everything the other one also has, counting repeats
+// so an id twice on the left and once on the right leaves one on the left
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Difference {
+    pub(crate) left_only: Vec<(u32, usize)>,
+    pub(crate) right_only: Vec<(u32, usize)>,
+}
+
+impl Lists {
+    pub(crate) fn read(path: &str) -> Result<Self> {
+        let columns = Reader::with_columns(2).read_columns::<u32>(
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("puzzle-inputs")
+                .join(path),
+        )?;
+        let [left, right] = <[Vec<u32>; 2]>::try_from(columns).map_err(|_| "expected two columns")?;
+        Ok(Self { left, right })
+    }
+
+    pub(crate) fn pairs(&self) -> Vec<Pair> {
+        let (mut left, mut right) = (self.left.clone(), self.right.clone());
+        left.sort();
+        right.sort();
+        zip(left, right)
+            .enumerate()
+            .map(|(rank, (left, right))| Pair {
+                rank,
+                left,
+                right,
+                distance: left.abs_diff(right),
+            })
+            .collect()
+    }
+
+    // part 1's answer
+    pub(crate) fn distance(&self) -> u64 {
+        self.pairs().iter().map(|p| p.distance as u64).sum()
+    }
+
+    // the k pairs furthest apart, furthest first, and the smallest first among equally far ones
+    pub(crate) fn largest_mismatches(&self, k: usize) -> Vec<Pair> {
+        let mut pairs = self.pairs();
+        pairs.sort_by_key(|p| (std::cmp::Reverse(p.distance), p.rank));
+        pairs.truncate(k);
+        pairs
+    }
+
+    // how many times each id appears in each list
+    fn counts(&self) -> BTreeMap<u32, (usize, usize)> {
+        let mut result = BTreeMap::new();
+        for id in self.left.iter() {
+            result.entry(*id).or_insert((0, 0)).0 += 1;
+        }
+        for id in self.right.iter() {
+            result.entry(*id).or_insert((0, 0)).1 += 1;
+        }
+        result
+    }
+
+    // every id in both lists, in order
+    pub(crate) fn shared(&self) -> Vec<Shared> {
+        self.counts()
+            .into_iter()
+            .filter(|(_, (left, right))| *left > 0 && *right > 0)
+            .map(|(id, (left_count, right_count))| Shared {
+                id,
+                left_count,
+                right_count,
+                similarity: id as u64 * left_count as u64 * right_count as u64,
+            })
+            .collect()
+    }
+
+    // part 2's answer
+    pub(crate) fn similarity(&self) -> u64 {
+        self.shared().iter().map(|s| s.similarity).sum()
+    }
+
+    pub(crate) fn difference(&self) -> Difference {
+        let mut result = Difference::default();
+        for (id, (left, right)) in self.counts() {
+            if left > right {
+                result.left_only.push((id, left - right));
+            } else if right > left {
+                result.right_only.push((id, right - left));
+            }
+        }
+        result
+    }
+
+    pub(crate) fn analyse(&self, top: usize) -> Analysis {
+        Analysis {
+            len: self.left.len().min(self.right.len()),
+            distance: self.distance(),
+            similarity: self.similarity(),
+            largest_mismatches: self.largest_mismatches(top),
+            difference: self.difference(),
+            shared: self.shared(),
+        }
+    }
+}
+
+// everything about a pair of lists at once, for comparing them with lists that are known to be right
+#[derive(Debug, Clone)]
+pub(crate) struct Analysis {
+    pub(crate) len: usize,
+    pub(crate) distance: u64,
+    pub(crate) similarity: u64,
+    pub(crate) largest_mismatches: Vec<Pair>,
+    pub(crate) difference: Difference,
+    pub(crate) shared: Vec<Shared>,
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} pairs, distance {}, similarity {}", self.len, self.distance, self.similarity)?;
+
+        writeln!(f)?;
+        writeln!(f, "largest mismatches")?;
+        writeln!(f, "{:>6}  {:>10}  {:>10}  {:>10}", "rank", "left", "right", "distance")?;
+        for p in self.largest_mismatches.iter() {
+            writeln!(f, "{:>6}  {:>10}  {:>10}  {:>10}", p.rank + 1, p.left, p.right, p.distance)?;
+        }
+
+        writeln!(f)?;
+        let total = |ids: &[(u32, usize)]| ids.iter().map(|(_, count)| count).sum::<usize>();
+        for (name, ids) in [("left", &self.difference.left_only), ("right", &self.difference.right_only)] {
+            let listed = ids
+                .iter()
+                .map(|(id, count)| if *count == 1 { id.to_string() } else { format!("{id} x{count}") })
+                .collect::<Vec<_>>();
+            writeln!(f, "only in {name}: {} ids: {}", total(ids), listed.join(", "))?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "shared ids, by how much they add to the similarity score")?;
+        writeln!(f, "{:>10}  {:>5}  {:>5}  {:>12}", "id", "left", "right", "similarity")?;
+        let mut shared = self.shared.clone();
+        shared.sort_by_key(|s| (std::cmp::Reverse(s.similarity), s.id));
+        for s in shared {
+            writeln!(f, "{:>10}  {:>5}  {:>5}  {:>12}", s.id, s.left_count, s.right_count, s.similarity)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn analyse(path: &str, top: usize) -> Result<Analysis> {
+    Ok(Lists::read(path)?.analyse(top))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_sample() {
+        let lists = Lists::read("day01-sample.txt").unwrap();
+        let analysis = lists.analyse(2);
+        assert_eq!((analysis.distance, analysis.similarity), (11, 31));
+        let distances = lists.pairs().iter().map(|p| p.distance).collect::<Vec<_>>();
+        assert_eq!(distances, vec![2, 1, 0, 1, 2, 5]);
+        let largest = analysis.largest_mismatches.iter().map(|p| (p.left, p.right)).collect::<Vec<_>>();
+        assert_eq!(largest, vec![(4, 9), (1, 3)]);
+        assert_eq!(
+            analysis.difference,
+            Difference {
+                left_only: vec![(1, 1), (2, 1)],
+                right_only: vec![(5, 1), (9, 1)],
+            }
+        );
+        let shared = analysis.shared.iter().map(|s| (s.id, s.left_count, s.right_count, s.similarity)).collect::<Vec<_>>();
+        assert_eq!(shared, vec![(3, 3, 3, 27), (4, 1, 1, 4)]);
+        let report = analysis.to_string();
+        assert!(report.starts_with("6 pairs, distance 11, similarity 31\n"));
+        assert!(report.contains("only in left: 2 ids: 1, 2\n"));
+    }
+
+    #[test]
+    pub fn test_real() {
+        let lists = Lists::read("day01.txt").unwrap();
+        assert_eq!((lists.distance(), lists.similarity()), (1319616, 27267728));
+        assert_eq!(lists.largest_mismatches(usize::MAX).len(), lists.left.len());
+    }
+}
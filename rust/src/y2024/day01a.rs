@@ -0,0 +1,84 @@
+use std::{
+    fmt::Debug,
+    iter::zip,
+    num::ParseIntError,
+};
+
+use super::day01::{self, Lists};
+use crate::checked::{self, Wide};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<day01::Error> for Error {
+    fn from(value: day01::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<Wide<u32>> {
+    let Lists { mut left, mut right } = Lists::read(path)?;
+
+    // sort
+    left.sort();
+    right.sort();
+
+    // join back together and calculate result
+    Ok(checked::sum(
+        "day01a",
+        zip(left, right).map(|(left, right)| Wide::<u32>::from(left.abs_diff(right))),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day01-sample.txt").unwrap(), 11);
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day01.txt").unwrap(), 1319616);
+    }
+}
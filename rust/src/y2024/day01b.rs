@@ -1,17 +1,13 @@
 use std::{
     collections::HashMap,
-    env,
     fmt::Debug,
-    fs::File,
-    io::{BufRead, BufReader},
     num::ParseIntError,
-    path::Path,
 };
 
-use regex::Regex;
+use super::day01::{self, Lists};
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -39,54 +35,20 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<day01::Error> for Error {
+    fn from(value: day01::Error) -> Self {
+        Self(value.0)
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u32> {
-    let r = Regex::new(r"^(\d+)\s+(\d+)$")?;
-    let file_contents = BufReader::new(File::open(
-        Path::new(env!("CARGO_MANIFEST_DIR"))
-            .join("..")
-            .join("puzzle-inputs")
-            .join(path),
-    )?)
-    .lines()
-    // parse lines
-    .map(|line| {
-        // ignore empty lines
-        let line = line?;
-        let line = line.trim();
-        if line.is_empty() {
-            Ok(None)
-        } else {
-            let captures = r.captures(line).ok_or(format!("bad line: {line}"))?;
-            let (_, [left, right]) = captures.extract();
-            Ok(Some((left.to_string(), right.to_string())))
-        }
-    })
-    // break if we have an error
-    .collect::<Result<Vec<_>>>()?
-    .into_iter()
-    // remove empty lines
-    .flatten()
-    .collect::<Vec<_>>();
-
-    // split
-    let (left, right): (Vec<String>, Vec<String>) = file_contents.into_iter().unzip();
-
-    // parse into ints
-    let left = left
-        .into_iter()
-        .map(|x| Ok(x.parse::<u32>()?))
-        .collect::<Result<Vec<_>>>()?;
-    let right = right
-        .into_iter()
-        .map(|x| Ok(x.parse::<u32>()?))
-        .collect::<Result<Vec<_>>>()?;
+pub(crate) fn do_it(path: &str) -> Result<u32> {
+    let Lists { left, right } = Lists::read(path)?;
 
     // count how often each number in the right list appears
     let counts = right.into_iter().fold(HashMap::new(), |mut result, x| {
@@ -0,0 +1,295 @@
+use std::{env, fmt::Display, ops::RangeInclusive, path::Path};
+
+use crate::table::{self, Reader};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(pub(crate) String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+// which way the levels of a safe report have to go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Increasing,
+    Decreasing,
+    // one or the other, but the same all the way along, which is what the puzzle asks for
+    Either,
+}
+
+impl Direction {
+    // as a solver parameter, which can only be a number
+    pub(crate) fn from_param(value: u64) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Either),
+            1 => Ok(Self::Increasing),
+            2 => Ok(Self::Decreasing),
+            _ => Err(format!("direction should be 0 (either), 1 (increasing) or 2 (decreasing), got {value}"))?,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rules {
+    // how far each level can be from the one before, in the report's direction
+    pub(crate) steps: RangeInclusive<u32>,
+    pub(crate) direction: Direction,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            steps: 1..=3,
+            direction: Direction::Either,
+        }
+    }
+}
+
+impl Rules {
+    // the solver parameters removals, min_step, max_step and direction, in that order, as rules and removals
+    pub(crate) fn from_params(p: &[u64]) -> Result<(Self, usize)> {
+        let step = |name, value: u64| u32::try_from(value).map_err(|_| format!("{name} should fit in a u32, got {value}"));
+        let (min_step, max_step) = (step("min_step", p[1])?, step("max_step", p[2])?);
+        if min_step > max_step {
+            Err(format!("min_step should be no more than max_step, got {min_step} and {max_step}"))?;
+        }
+        let rules = Self {
+            steps: min_step..=max_step,
+            direction: Direction::from_param(p[3])?,
+        };
+        let removals = usize::try_from(p[0]).map_err(|_| format!("removals should fit in a usize, got {}", p[0]))?;
+        Ok((rules, removals))
+    }
+
+    fn allows(&self, increasing: bool, a: i32, b: i32) -> bool {
+        let step = if increasing { b as i64 - a as i64 } else { a as i64 - b as i64 };
+        step >= *self.steps.start() as i64 && step <= *self.steps.end() as i64
+    }
+
+    pub(crate) fn is_safe(&self, report: &[i32]) -> bool {
+        self.dampen(report, 0).is_some()
+    }
+
+    // the fewest levels to take out of the report to make it safe, by index, as long as that's no more than
+    // removals, when there's a choice of which ones this is any of them
+    // this is O(n * (removals + 1)), since once two kept levels are further apart than that too much has gone
+    pub(crate) fn dampen(&self, report: &[i32], removals: usize) -> Option<Vec<usize>> {
+        let directions: &[bool] = match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either => &[true, false],
+        };
+        directions
+            .iter()
+            .filter_map(|increasing| self.dampen_in(*increasing, report, removals))
+            .min_by_key(|removed| removed.len())
+    }
+
+    fn dampen_in(&self, increasing: bool, report: &[i32], removals: usize) -> Option<Vec<usize>> {
+        let n = report.len();
+        if n == 0 {
+            return Some(Vec::new());
+        }
+        // removed[j] is the fewest levels taken out before j when j is kept, and from[j] the kept level before it
+        let mut removed = vec![usize::MAX; n];
+        let mut from = vec![None; n];
+        for j in 0..n {
+            if j <= removals {
+                removed[j] = j;
+            }
+            for i in j.saturating_sub(removals.saturating_add(1))..j {
+                if removed[i] == usize::MAX || !self.allows(increasing, report[i], report[j]) {
+                    continue;
+                }
+                let total = removed[i] + (j - i - 1);
+                if total < removed[j] {
+                    removed[j] = total;
+                    from[j] = Some(i);
+                }
+            }
+        }
+
+        let (last, total) = (0..n)
+            .filter(|j| removed[*j] != usize::MAX)
+            .map(|j| (j, removed[j] + (n - 1 - j)))
+            .min_by_key(|(_, total)| *total)?;
+        if total > removals {
+            return None;
+        }
+        let mut kept = vec![false; n];
+        let mut current = Some(last);
+        while let Some(j) = current {
+            kept[j] = true;
+            current = from[j];
+        }
+        Some((0..n).filter(|i| !kept[*i]).collect())
+    }
+}
+
+pub(crate) fn read(path: &str) -> Result<Vec<Vec<i32>>> {
+    Ok(Reader::default().read_rows::<i32>(
+        &Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+}
+
+pub(crate) fn count_safe(path: &str, rules: &Rules, removals: usize) -> Result<usize> {
+    Ok(read(path)?
+        .iter()
+        .filter(|report| rules.dampen(report, removals).is_some())
+        .count())
+}
+
+// a report that isn't safe as it is, and what it would take to make it safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Unsafe {
+    // counting from 1, like the input's lines
+    pub(crate) report: usize,
+    pub(crate) levels: Vec<i32>,
+    // None when it takes more than the removals allowed
+    pub(crate) removed: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Analysis {
+    pub(crate) rules: Rules,
+    pub(crate) removals: usize,
+    pub(crate) reports: usize,
+    pub(crate) safe: usize,
+    pub(crate) unsafe_reports: Vec<Unsafe>,
+}
+
+pub(crate) fn analyse(path: &str, rules: Rules, removals: usize) -> Result<Analysis> {
+    let reports = read(path)?;
+    let unsafe_reports = reports
+        .iter()
+        .enumerate()
+        .filter(|(_, levels)| !rules.is_safe(levels))
+        .map(|(i, levels)| Unsafe {
+            report: i + 1,
+            levels: levels.clone(),
+            removed: rules.dampen(levels, removals),
+        })
+        .collect::<Vec<_>>();
+    Ok(Analysis {
+        removals,
+        reports: reports.len(),
+        safe: reports.len() - unsafe_reports.len(),
+        unsafe_reports,
+        rules,
+    })
+}
+
+pub(crate) fn analyse_with_params(path: &str, params: &[u64]) -> Result<Analysis> {
+    let (rules, removals) = Rules::from_params(params)?;
+    analyse(path, rules, removals)
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let dampened = self.unsafe_reports.iter().filter(|u| u.removed.is_some()).count();
+        writeln!(
+            f,
+            "{} reports with steps {}..={} {:?}: {} safe, {} more with up to {} removed, {} not",
+            self.reports,
+            self.rules.steps.start(),
+            self.rules.steps.end(),
+            self.rules.direction,
+            self.safe,
+            dampened,
+            self.removals,
+            self.unsafe_reports.len() - dampened
+        )?;
+
+        writeln!(f)?;
+        writeln!(f, "{:>6}  {:>8}  levels, with removed ones in brackets", "report", "removed")?;
+        for u in self.unsafe_reports.iter() {
+            let levels = u
+                .levels
+                .iter()
+                .enumerate()
+                .map(|(i, level)| match &u.removed {
+                    Some(removed) if removed.contains(&i) => format!("[{level}]"),
+                    _ => level.to_string(),
+                })
+                .collect::<Vec<_>>();
+            let removed = u.removed.as_ref().map_or("-".to_string(), |r| r.len().to_string());
+            writeln!(f, "{:>6}  {:>8}  {}", u.report, removed, levels.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_dampen() {
+        let rules = Rules::default();
+        assert_eq!(rules.dampen(&[7, 6, 4, 2, 1], 1), Some(vec![]));
+        assert_eq!(rules.dampen(&[1, 2, 7, 8, 9], 1), None);
+        assert_eq!(rules.dampen(&[1, 3, 2, 4, 5], 1), Some(vec![2]));
+        assert_eq!(rules.dampen(&[8, 6, 4, 4, 1], 1).map(|r| r.len()), Some(1));
+        // the first and last levels can go too
+        assert_eq!(rules.dampen(&[9, 1, 2, 3], 1), Some(vec![0]));
+        assert_eq!(rules.dampen(&[1, 2, 3, 9], 1), Some(vec![3]));
+        assert_eq!(rules.dampen(&[1, 9, 9, 2, 3], 1), None);
+        assert_eq!(rules.dampen(&[1, 9, 9, 2, 3], 2), Some(vec![1, 2]));
+        assert_eq!(rules.dampen(&[], 0), Some(vec![]));
+        assert_eq!(rules.dampen(&[5], 0), Some(vec![]));
+        // more removals than levels is the same as removing any number of them
+        assert_eq!(rules.dampen(&[1, 9, 9, 2, 3], usize::MAX), Some(vec![1, 2]));
+
+        let increasing = Rules {
+            direction: Direction::Increasing,
+            ..Rules::default()
+        };
+        assert_eq!(increasing.dampen(&[7, 6, 4, 2, 1], 3), None);
+        assert_eq!(increasing.dampen(&[7, 6, 4, 2, 1], 4), Some(vec![1, 2, 3, 4]));
+        let flat = Rules {
+            steps: 0..=1,
+            direction: Direction::Decreasing,
+        };
+        assert!(flat.is_safe(&[3, 3, 2, 2, 1]));
+        assert!(!flat.is_safe(&[3, 3, 1]));
+        assert_eq!(Rules::from_params(&[1, 0, 1, 2]).unwrap(), (flat, 1));
+        assert!(Rules::from_params(&[1, 1, 3, 3]).is_err());
+        assert!(Rules::from_params(&[1, 3, 1, 0]).is_err());
+        assert!(Rules::from_params(&[1, 1, 1 << 32, 0]).is_err());
+        let (rules, removals) = Rules::from_params(&[u64::MAX, 1, 3, 0]).unwrap();
+        assert_eq!(rules.dampen(&[1, 9, 9, 2, 3], removals), Some(vec![1, 2]));
+    }
+
+    #[test]
+    pub fn test_sample() {
+        let rules = Rules::default();
+        assert_eq!(count_safe("day02-sample.txt", &rules, 0).unwrap(), 2);
+        assert_eq!(count_safe("day02-sample.txt", &rules, 1).unwrap(), 4);
+        assert_eq!(count_safe("day02-sample.txt", &rules, 2).unwrap(), 6);
+        let analysis = analyse("day02-sample.txt", rules, 1).unwrap();
+        let report = analysis.to_string();
+        assert!(report.starts_with("6 reports with steps 1..=3 Either: 2 safe, 2 more with up to 1 removed, 2 not\n"));
+        assert!(report.contains("     4         1  1 3 [2] 4 5\n"));
+        assert!(report.contains("     2         -  1 2 7 8 9\n"));
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fmt::Debug, num::ParseIntError};
+
+use super::day02::{self, Rules};
+use crate::table;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<day02::Error> for Error {
+    fn from(value: day02::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u32> {
+    Ok(day02::count_safe(path, &Rules::default(), 0)? as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day02-sample.txt").unwrap(), 2);
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day02.txt").unwrap(), 572);
+    }
+}
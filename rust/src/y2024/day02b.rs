@@ -0,0 +1,115 @@
+use std::{fmt::Debug, num::ParseIntError};
+
+use super::day02::{self, Rules};
+use crate::table;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<day02::Error> for Error {
+    fn from(value: day02::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+// params are removals, min_step, max_step and direction, see Rules::from_params
+pub(crate) fn do_it(path: &str, params: &[u64]) -> Result<u32> {
+    let (rules, removals) = Rules::from_params(params)?;
+    Ok(day02::count_safe(path, &rules, removals)? as u32)
+}
+
+// trying every level removal by rebuilding the report without it, which is O(n^removals) per report
+pub(crate) fn do_it_by_rebuilding(path: &str, params: &[u64]) -> Result<u32> {
+    let (rules, removals) = Rules::from_params(params)?;
+    Ok(day02::read(path)?
+        .into_iter()
+        .filter(|line| can_be_made_good(&rules, line, removals))
+        .count() as u32)
+}
+
+fn can_be_made_good(rules: &Rules, line: &[i32], removals: usize) -> bool {
+    rules.is_safe(line)
+        || (removals > 0
+            && (0..line.len()).any(|i| can_be_made_good(rules, &duplicate_without_index(line, i), removals - 1)))
+}
+
+fn duplicate_without_index<T>(source: &[T], to_remove: usize) -> Vec<T>
+where
+    T: Clone,
+{
+    source
+        .iter()
+        .enumerate()
+        .filter_map(|(i, x)| {
+            if i != to_remove {
+                Some(x.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{do_it, do_it_by_rebuilding};
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day02-sample.txt", &[1, 1, 3, 0]).unwrap(), 4);
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day02.txt", &[1, 1, 3, 0]).unwrap(), 612);
+    }
+
+    #[test]
+    pub fn test_more_removals() {
+        for removals in 0..=3 {
+            for direction in 0..=2 {
+                let params = [removals, 1, 3, direction];
+                assert_eq!(do_it("day02.txt", &params).unwrap(), do_it_by_rebuilding("day02.txt", &params).unwrap());
+            }
+        }
+        assert_eq!(do_it("day02.txt", &[0, 1, 3, 0]).unwrap(), 572);
+    }
+}
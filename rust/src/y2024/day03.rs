@@ -0,0 +1,373 @@
+use std::{env, fmt::Display, ops::Range, path::Path};
+
+use crate::checked::{self, Wide};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(pub(crate) String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
+// what the program's instructions act on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Machine {
+    // the solver running it, which overflow errors name
+    pub(crate) day: &'static str,
+    pub(crate) enabled: bool,
+    pub(crate) sum: Wide<u32>,
+}
+
+impl Machine {
+    pub(crate) fn new(day: &'static str) -> Self {
+        Self {
+            day,
+            enabled: true,
+            sum: 0,
+        }
+    }
+}
+
+// something that can appear in the program as name(a,b,...), with each argument 1 to 3 digits
+pub(crate) trait Instruction: Sync {
+    fn name(&self) -> &'static str;
+    fn arity(&self) -> usize;
+    // false when it was accepted but had nothing to do, like mul while disabled
+    fn execute(&self, machine: &mut Machine, args: &[u32]) -> Result<bool>;
+}
+
+pub(crate) struct Mul;
+
+impl Instruction for Mul {
+    fn name(&self) -> &'static str {
+        "mul"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, machine: &mut Machine, args: &[u32]) -> Result<bool> {
+        if !machine.enabled {
+            return Ok(false);
+        }
+        let product = checked::mul(machine.day, Wide::<u32>::from(args[0]), Wide::<u32>::from(args[1]))?;
+        machine.sum = checked::add(machine.day, machine.sum, product)?;
+        Ok(true)
+    }
+}
+
+pub(crate) struct Do;
+
+impl Instruction for Do {
+    fn name(&self) -> &'static str {
+        "do"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<bool> {
+        machine.enabled = true;
+        Ok(true)
+    }
+}
+
+pub(crate) struct Dont;
+
+impl Instruction for Dont {
+    fn name(&self) -> &'static str {
+        "don't"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<bool> {
+        machine.enabled = false;
+        Ok(true)
+    }
+}
+
+pub(crate) static PART_A: &[&dyn Instruction] = &[&Mul];
+pub(crate) static PART_B: &[&dyn Instruction] = &[&Mul, &Do, &Dont];
+
+// somewhere an instruction's name appears, and whether what follows it makes it a real instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Candidate {
+    // byte offsets into the program; a rejected candidate ends where the problem is, or at the end of the program
+    pub(crate) span: Range<usize>,
+    // index into the instruction set
+    pub(crate) instruction: usize,
+    pub(crate) args: std::result::Result<Vec<u32>, String>,
+}
+
+// parses the argument list starting at the ( after a name, giving the arguments and the end of the )
+fn parse_args(program: &[u8], start: usize, arity: usize) -> std::result::Result<(Vec<u32>, usize), (String, usize)> {
+    let describe = |i: usize| match program.get(i) {
+        Some(b) => format!("{:?}", *b as char),
+        None => "the end".to_string(),
+    };
+    let mut i = start;
+    if program.get(i) != Some(&b'(') {
+        return Err((format!("expected '(', got {}", describe(i)), i));
+    }
+    i += 1;
+    let mut args = Vec::with_capacity(arity);
+    while args.len() < arity {
+        if !args.is_empty() {
+            if program.get(i) != Some(&b',') {
+                return Err((format!("expected ',', got {}", describe(i)), i));
+            }
+            i += 1;
+        }
+        let digits = program[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        match digits {
+            0 => return Err((format!("expected a digit, got {}", describe(i)), i)),
+            1..=3 => {}
+            _ => return Err((format!("expected at most 3 digits, got {digits}"), i + 3)),
+        }
+        args.push(program[i..i + digits].iter().fold(0, |n, b| n * 10 + (b - b'0') as u32));
+        i += digits;
+    }
+    if program.get(i) != Some(&b')') {
+        return Err((format!("expected ')', got {}", describe(i)), i));
+    }
+    Ok((args, i + 1))
+}
+
+// every candidate in the program in order, in one pass over the bytes
+// an accepted instruction is skipped over, but scanning carries on from the byte after a rejected one's name
+// starts, since a real instruction could be hiding inside it, as in mul(mul(2,3)
+// line breaks are just bytes, so an instruction can't be split across lines
+pub(crate) fn lex(program: &[u8], instructions: &[&dyn Instruction]) -> Vec<Candidate> {
+    // longest first, so don't() is tried before do()
+    let mut order = (0..instructions.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| std::cmp::Reverse(instructions[*i].name().len()));
+
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < program.len() {
+        let mut rejected = None;
+        let mut accepted = None;
+        for &index in order.iter() {
+            let name = instructions[index].name().as_bytes();
+            if !program[i..].starts_with(name) {
+                continue;
+            }
+            match parse_args(program, i + name.len(), instructions[index].arity()) {
+                Ok((args, end)) => {
+                    accepted = Some(Candidate {
+                        span: i..end,
+                        instruction: index,
+                        args: Ok(args),
+                    });
+                    break;
+                }
+                // the longest name is the one that got furthest
+                Err((reason, end)) => {
+                    rejected.get_or_insert(Candidate {
+                        span: i..end,
+                        instruction: index,
+                        args: Err(reason),
+                    });
+                }
+            }
+        }
+        match accepted.or(rejected) {
+            Some(candidate) => {
+                i = if candidate.args.is_ok() { candidate.span.end } else { i + 1 };
+                result.push(candidate);
+            }
+            None => i += 1,
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Executed,
+    // accepted, but it had nothing to do
+    Ignored,
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Step {
+    pub(crate) candidate: Candidate,
+    pub(crate) outcome: Outcome,
+    // the sum after it
+    pub(crate) sum: Wide<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Trace {
+    pub(crate) program: Vec<u8>,
+    pub(crate) steps: Vec<Step>,
+    pub(crate) machine: Machine,
+}
+
+pub(crate) fn run(day: &'static str, program: &[u8], instructions: &[&dyn Instruction]) -> Result<Trace> {
+    let mut machine = Machine::new(day);
+    let mut steps = Vec::new();
+    for candidate in lex(program, instructions) {
+        let outcome = match &candidate.args {
+            Ok(args) => match instructions[candidate.instruction].execute(&mut machine, args)? {
+                true => Outcome::Executed,
+                false => Outcome::Ignored,
+            },
+            Err(_) => Outcome::Rejected,
+        };
+        steps.push(Step {
+            candidate,
+            outcome,
+            sum: machine.sum,
+        });
+    }
+    Ok(Trace {
+        program: program.to_vec(),
+        steps,
+        machine,
+    })
+}
+
+pub(crate) fn read(path: &str) -> Result<Vec<u8>> {
+    Ok(std::fs::read(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+}
+
+pub(crate) fn sum(day: &'static str, path: &str, instructions: &[&dyn Instruction]) -> Result<Wide<u32>> {
+    Ok(run(day, &read(path)?, instructions)?.machine.sum)
+}
+
+pub(crate) fn analyse(path: &str) -> Result<Trace> {
+    run("day03b", &read(path)?, PART_B)
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count = |outcome| self.steps.iter().filter(|s| s.outcome == outcome).count();
+        writeln!(
+            f,
+            "sum {}: {} executed, {} ignored, {} rejected",
+            self.machine.sum,
+            count(Outcome::Executed),
+            count(Outcome::Ignored),
+            count(Outcome::Rejected)
+        )?;
+        writeln!(f)?;
+        writeln!(f, "{:>13}  {:<8}  {:>12}  text", "bytes", "outcome", "sum")?;
+        for step in self.steps.iter() {
+            let span = &step.candidate.span;
+            let text = String::from_utf8_lossy(&self.program[span.clone()]);
+            let outcome = format!("{:?}", step.outcome).to_lowercase();
+            write!(f, "{:>13}  {:<8}  {:>12}  {:?}", format!("{}..{}", span.start, span.end), outcome, step.sum, text)?;
+            if let Err(reason) = &step.candidate.args {
+                write!(f, ": {reason}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(program: &str, instructions: &[&dyn Instruction]) -> Vec<(Range<usize>, &'static str, bool)> {
+        lex(program.as_bytes(), instructions)
+            .into_iter()
+            .map(|c| (c.span, instructions[c.instruction].name(), c.args.is_ok()))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_lex() {
+        assert_eq!(
+            spans("xmul(2,4)%mul[3,7]mul(32,64]mul(1234,5)", PART_A),
+            vec![(1..9, "mul", true), (10..13, "mul", false), (18..27, "mul", false), (28..35, "mul", false)]
+        );
+        assert_eq!(
+            spans("do_not_mul(5,5)don't()undo()", PART_B),
+            vec![(0..2, "do", false), (7..15, "mul", true), (15..22, "don't", true), (24..28, "do", true)]
+        );
+        // a real instruction inside a rejected one, and one split across lines
+        assert_eq!(spans("mul(mul(2,3)", PART_A), vec![(0..4, "mul", false), (4..12, "mul", true)]);
+        assert_eq!(spans("mul(1,\n2)", PART_A), vec![(0..6, "mul", false)]);
+        let rejected = lex(b"mul(4*", PART_A).remove(0);
+        assert_eq!(rejected.args, Err("expected ',', got '*'".to_string()));
+        let rejected = lex(b"mul(4,", PART_A).remove(0);
+        assert_eq!(rejected.args, Err("expected a digit, got the end".to_string()));
+    }
+
+    #[test]
+    pub fn test_sample() {
+        let trace = run("day03b", &read("day03b-sample.txt").unwrap(), PART_B).unwrap();
+        assert_eq!(trace.machine.sum, 48);
+        let outcomes = trace.steps.iter().map(|s| s.outcome).collect::<Vec<_>>();
+        use Outcome::*;
+        assert_eq!(
+            outcomes,
+            vec![Executed, Rejected, Executed, Ignored, Rejected, Ignored, Executed, Executed]
+        );
+        let report = trace.to_string();
+        assert!(report.starts_with("sum 48: 4 executed, 2 ignored, 2 rejected\n"));
+        assert!(report.contains("       37..46  rejected             8  \"mul(32,64\": expected ')', got ']'\n"));
+        assert_eq!(sum("day03a", "day03a-sample.txt", PART_A).unwrap(), 161);
+    }
+
+    #[test]
+    pub fn test_new_instruction() {
+        struct Add;
+
+        impl Instruction for Add {
+            fn name(&self) -> &'static str {
+                "add"
+            }
+
+            fn arity(&self) -> usize {
+                3
+            }
+
+            fn execute(&self, machine: &mut Machine, args: &[u32]) -> Result<bool> {
+                machine.sum += args.iter().map(|a| Wide::<u32>::from(*a)).sum::<Wide<u32>>();
+                Ok(true)
+            }
+        }
+
+        let trace = run("test", b"add(1,2,3)don't()add(4,5)mul(2,2)", &[&Mul as &dyn Instruction, &Dont, &Add]).unwrap();
+        assert_eq!(trace.machine.sum, 6);
+        let outcomes = trace.steps.iter().map(|s| s.outcome).collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![Outcome::Executed, Outcome::Executed, Outcome::Rejected, Outcome::Ignored]);
+    }
+}
@@ -9,8 +9,11 @@ use std::{
 
 use regex::Regex;
 
+use super::day03;
+use crate::checked::{self, Wide};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -38,14 +41,30 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<day03::Error> for Error {
+    fn from(value: day03::Error) -> Self {
+        Self(value.0)
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u32> {
+pub(crate) fn do_it(path: &str) -> Result<Wide<u32>> {
+    Ok(day03::sum("day03a", path, day03::PART_A)?)
+}
+
+// joins the lines together and searches them with a regex
+pub(crate) fn do_it_with_regex(path: &str) -> Result<Wide<u32>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -65,14 +84,15 @@ fn do_it(path: &str) -> Result<u32> {
 
     let file_contents = file_contents.join("");
     let r = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)")?;
-    Ok(r.captures_iter(&file_contents)
-        .filter_map(|x| {
-            let (_, [left, right]) = x.extract();
-            let left = left.parse::<u32>().ok()?;
-            let right = right.parse::<u32>().ok()?;
-            Some(left * right)
-        })
-        .sum())
+    let mut sum: Wide<u32> = 0;
+    for x in r.captures_iter(&file_contents) {
+        let (_, [left, right]) = x.extract();
+        if let (Ok(left), Ok(right)) = (left.parse::<u32>(), right.parse::<u32>()) {
+            let product = checked::mul("day03a", Wide::<u32>::from(left), Wide::<u32>::from(right))?;
+            sum = checked::add("day03a", sum, product)?;
+        }
+    }
+    Ok(sum)
 }
 
 #[cfg(test)]
@@ -88,4 +108,15 @@ mod tests {
     pub fn test_real() {
         assert_eq!(do_it("day03.txt").unwrap(), 192767529);
     }
+
+    #[cfg(feature = "checked-arithmetic")]
+    #[test]
+    pub fn test_past_u32() {
+        // 5000 * 999 * 999 is more than a u32 holds
+        let path = std::env::temp_dir().join(format!("day03a-past-u32-{}.txt", std::process::id()));
+        std::fs::write(&path, "mul(999,999)".repeat(5000)).unwrap();
+        let result = do_it(&path.to_string_lossy());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), 5000 * 999 * 999);
+    }
 }
@@ -9,8 +9,11 @@ use std::{
 
 use regex::Regex;
 
+use super::day03;
+use crate::checked::{self, Wide};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -38,14 +41,30 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<day03::Error> for Error {
+    fn from(value: day03::Error) -> Self {
+        Self(value.0)
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u32> {
+pub(crate) fn do_it(path: &str) -> Result<Wide<u32>> {
+    Ok(day03::sum("day03b", path, day03::PART_B)?)
+}
+
+// joins the lines together and searches them with a regex at every byte offset, which is O(n * k)
+pub(crate) fn do_it_with_regex(path: &str) -> Result<Wide<u32>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -66,7 +85,7 @@ fn do_it(path: &str) -> Result<u32> {
     let file_contents = file_contents.join("");
     let r = Regex::new(r"^mul\(([0-9]{1,3}),([0-9]{1,3})\)")?;
     let mut enabled = true;
-    let mut sum = 0;
+    let mut sum: Wide<u32> = 0;
     for i in 0..file_contents.len() {
         if file_contents[i..].starts_with("do()") {
             enabled = true;
@@ -77,7 +96,8 @@ fn do_it(path: &str) -> Result<u32> {
                 let (_, [left, right]) = captures.extract();
                 if let Ok(left) = left.parse::<u32>() {
                     if let Ok(right) = right.parse::<u32>() {
-                        sum += left * right;
+                        let product = checked::mul("day03b", Wide::<u32>::from(left), Wide::<u32>::from(right))?;
+                        sum = checked::add("day03b", sum, product)?;
                     }
                 }
             }
@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, env, fmt::Display, path::Path};
+
+use crate::{
+    grid_view::{self, Grid},
+    pattern::{self, Matcher, Orientation, Pattern, Symmetry},
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(pub(crate) String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<grid_view::Error> for Error {
+    fn from(value: grid_view::Error) -> Self {
+        Self(format!("grid_view::Error({value:?})"))
+    }
+}
+
+impl From<pattern::Error> for Error {
+    fn from(value: pattern::Error) -> Self {
+        Self(format!("pattern::Error({value:?})"))
+    }
+}
+
+pub(crate) fn read(path: &str) -> Result<Grid<char>> {
+    let file_contents = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?;
+    let lines = file_contents.lines().map(|line| line.trim()).collect::<Vec<_>>();
+    Ok(Grid::parse(&lines)?)
+}
+
+// part 1: XMAS in any of the eight directions
+pub(crate) fn xmas() -> Result<Pattern<char>> {
+    Ok(Pattern::word("XMAS")?)
+}
+
+// part 2: two MAS crossing at the A, either way round, so any rotation or reflection of this
+pub(crate) fn x_mas() -> Result<Pattern<char>> {
+    Ok(Pattern::parse("X-MAS", &["M.S", ".A.", "M.S"], '.', Symmetry::All)?)
+}
+
+pub(crate) fn count(path: &str, pattern: Pattern<char>) -> Result<u32> {
+    let grid = read(path)?;
+    Ok(Matcher::new(vec![pattern]).find_all(&grid.view()).len() as u32)
+}
+
+// how many of each pattern there are, which way round, from one pass over the grid
+#[derive(Debug, Clone)]
+pub(crate) struct Analysis {
+    pub(crate) names: Vec<String>,
+    pub(crate) counts: Vec<BTreeMap<(usize, Orientation), usize>>,
+}
+
+pub(crate) fn analyse(path: &str) -> Result<Analysis> {
+    let grid = read(path)?;
+    let matcher = Matcher::new(vec![xmas()?, x_mas()?]);
+    let mut counts = vec![BTreeMap::new(); matcher.patterns().len()];
+    for m in matcher.find_all(&grid.view()) {
+        *counts[m.pattern].entry((m.shape, m.orientation)).or_insert(0) += 1;
+    }
+    Ok(Analysis {
+        names: matcher.patterns().iter().map(|p| p.name.clone()).collect(),
+        counts,
+    })
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<8}  {:>5}  {:<16}  {:>6}", "pattern", "shape", "orientation", "count")?;
+        for (name, counts) in self.names.iter().zip(self.counts.iter()) {
+            for ((shape, orientation), count) in counts {
+                writeln!(f, "{:<8}  {:>5}  {:<16}  {:>6}", name, shape, orientation.to_string(), count)?;
+            }
+            writeln!(f, "{:<8}  {:>5}  {:<16}  {:>6}", name, "", "total", counts.values().sum::<usize>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_sample() {
+        let analysis = analyse("day04a-sample2.txt").unwrap();
+        assert_eq!(analysis.names, vec!["XMAS", "X-MAS"]);
+        let totals = analysis.counts.iter().map(|c| c.values().sum::<usize>()).collect::<Vec<_>>();
+        assert_eq!(totals, vec![18, 9]);
+        // across the right way is shape 0 upright, and there are 3 of them
+        let upright = Orientation {
+            flipped: false,
+            quarter_turns: 0,
+        };
+        assert_eq!(analysis.counts[0][&(0, upright)], 3);
+        assert!(analysis.to_string().contains("X-MAS            total                  9\n"));
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    env,
+    fmt::Debug,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::ParseIntError,
+    path::Path,
+};
+
+use super::day04;
+use crate::grid_view::{self, Grid, View};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+impl From<day04::Error> for Error {
+    fn from(value: day04::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<grid_view::Error> for Error {
+    fn from(value: grid_view::Error) -> Self {
+        Self(format!("grid_view::Error({value:?})"))
+    }
+}
+
+// whether word reads from (x, y) in steps of (dx, dy)
+fn is_word(view: &View<char>, x: usize, y: usize, (dx, dy): (i64, i64), word: &str) -> bool {
+    word.chars().enumerate().all(|(i, c)| {
+        let i = i as i64;
+        view.get_signed(x as i64 + dx * i, y as i64 + dy * i) == Some(&c)
+    })
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u32> {
+    Ok(day04::count(path, day04::xmas()?)?)
+}
+
+// checking right and diagonally down right in each rotation of the grid, rather than rotating the pattern
+pub(crate) fn do_it_with_rotations(path: &str) -> Result<u32> {
+    let file_contents = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+    .lines()
+    // parse lines
+    .map(|line| {
+        // ignore empty lines
+        let line = line?;
+        let line = line.trim();
+        Ok(line.to_string())
+    })
+    // break if we have an error
+    .collect::<Result<Vec<_>>>()?;
+
+    let grid = Grid::parse(&file_contents)?;
+
+    // the eight directions are right and diagonally down right, in each of the four rotations
+    let mut count = 0;
+    for view in (0..4).map(|turns| grid.view().rotate(turns)) {
+        for y in 0..view.height() {
+            for x in 0..view.width() {
+                for direction in [(1, 0), (1, 1)] {
+                    if is_word(&view, x, y, direction, "XMAS") {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample1() {
+        assert_eq!(do_it("day04a-sample1.txt").unwrap(), 4);
+    }
+
+    #[test]
+    pub fn test_sample2() {
+        assert_eq!(do_it("day04a-sample2.txt").unwrap(), 18);
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day04.txt").unwrap(), 2543);
+    }
+}
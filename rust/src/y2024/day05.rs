@@ -0,0 +1,767 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    env,
+    fmt::Display,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::ParseIntError,
+    path::Path,
+};
+
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(pub(crate) String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rule {
+    pub(crate) left: u32,
+    pub(crate) right: u32,
+}
+
+impl Rule {
+    fn new(left: &str, right: &str) -> Result<Self> {
+        Ok(Self {
+            left: left.parse()?,
+            right: right.parse()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Rules {
+    pub(crate) rules: Vec<Rule>,
+    pub(crate) rules_by_number: Vec<Vec<Rule>>,
+    pub(crate) grid: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    pub(crate) fn new(rules: Vec<Rule>) -> Self {
+        let max_num = rules
+            .iter()
+            .map(|rule| rule.left.max(rule.right))
+            .max()
+            .unwrap_or(0) as usize
+            + 1;
+
+        let mut rules_by_number = (0..max_num).map(|_| Vec::new()).collect::<Vec<_>>();
+        for rule in rules.iter() {
+            rules_by_number[rule.left as usize].push(rule.clone());
+        }
+
+        let mut grid = Vec::with_capacity(max_num);
+        for left in 0..max_num {
+            let mut row = Vec::with_capacity(max_num);
+            for right in 0..max_num {
+                row.push(rules.contains(&Rule {
+                    left: left as u32,
+                    right: right as u32,
+                }));
+            }
+            grid.push(row);
+        }
+
+        Self {
+            rules,
+            rules_by_number,
+            grid,
+        }
+    }
+
+    pub(crate) fn new_with_restricted_numbers(other: &Rules, numbers: &[u32]) -> Self {
+        Self::new(
+            other
+                .rules
+                .iter()
+                .filter_map(|rule| {
+                    if numbers.contains(&rule.left) && numbers.contains(&rule.right) {
+                        Some(rule.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub(crate) fn check(&self, left: u32, right: u32) -> bool {
+        let left = left as usize;
+        if left < self.grid.len() {
+            let row = &self.grid[left];
+            let right = right as usize;
+            if right < row.len() {
+                row[right]
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn possible_choices(&self, left: u32) -> Option<&Vec<Rule>> {
+        if (left as usize) < self.rules_by_number.len() {
+            Some(&self.rules_by_number[left as usize])
+        } else {
+            None
+        }
+    }
+
+    // for each page in numbers, by index, the indices of the pages that have to come before it
+    fn predecessors(&self, numbers: &[u32]) -> Vec<Vec<usize>> {
+        let index = numbers.iter().enumerate().map(|(i, n)| (*n, i)).collect::<HashMap<_, _>>();
+        let mut result = vec![Vec::new(); numbers.len()];
+        for rule in self.rules.iter() {
+            if let (Some(left), Some(right)) = (index.get(&rule.left), index.get(&rule.right)) {
+                result[*right].push(*left);
+            }
+        }
+        result
+    }
+
+    // the pages of an update in an order that breaks none of the rules between them, taking the smallest page
+    // whenever more than one could come next, or the rules that go round in a circle if there's no such order
+    pub(crate) fn topological_order(&self, numbers: &[u32]) -> std::result::Result<Ordered, Cycle> {
+        order_from(numbers, &self.predecessors(numbers))
+    }
+
+    // how many orders of the update's pages break none of the rules, or None if there are more than max_pages
+    // pages and more than one order, since it goes through every subset of the pages
+    pub(crate) fn count_orderings(&self, numbers: &[u32], max_pages: usize) -> Option<u64> {
+        match self.topological_order(numbers) {
+            Err(_) => return Some(0),
+            Ok(ordered) if ordered.is_unique() => return Some(1),
+            Ok(_) if numbers.len() > max_pages.min(MAX_COUNTED_PAGES) => return None,
+            Ok(_) => {}
+        }
+        let before = self
+            .predecessors(numbers)
+            .iter()
+            .map(|p| p.iter().fold(0u32, |mask, i| mask | 1 << i))
+            .collect::<Vec<_>>();
+        // ways[placed] is how many ways there are of putting the pages in placed first
+        let mut ways = vec![0u64; 1 << numbers.len()];
+        ways[0] = 1;
+        for placed in 0..ways.len() {
+            if ways[placed] == 0 {
+                continue;
+            }
+            for (i, before) in before.iter().enumerate() {
+                if placed & (1 << i) == 0 && placed as u32 & before == *before {
+                    ways[placed | 1 << i] += ways[placed];
+                }
+            }
+        }
+        Some(ways[ways.len() - 1])
+    }
+}
+
+// topological_order for pages given by the pages that have to come before each of them
+fn order_from(numbers: &[u32], predecessors: &[Vec<usize>]) -> std::result::Result<Ordered, Cycle> {
+    let mut successors = vec![Vec::new(); numbers.len()];
+    for (right, lefts) in predecessors.iter().enumerate() {
+        for left in lefts {
+            successors[*left].push(right);
+        }
+    }
+    let mut waiting_for = predecessors.iter().map(|p| p.len()).collect::<Vec<_>>();
+    let mut ready = (0..numbers.len())
+        .filter(|i| waiting_for[*i] == 0)
+        .map(|i| (numbers[i], i))
+        .collect::<BTreeSet<_>>();
+
+    let mut order = Vec::with_capacity(numbers.len());
+    let mut ambiguity = None;
+    while let Some((page, i)) = ready.pop_first() {
+        if let (None, Some((other, _))) = (ambiguity, ready.first()) {
+            ambiguity = Some((page, *other));
+        }
+        order.push(page);
+        for next in successors[i].iter() {
+            waiting_for[*next] -= 1;
+            if waiting_for[*next] == 0 {
+                ready.insert((numbers[*next], *next));
+            }
+        }
+    }
+
+    if order.len() < numbers.len() {
+        return Err(Cycle::find(numbers, predecessors, &waiting_for));
+    }
+    Ok(Ordered { order, ambiguity })
+}
+
+// 20! orderings still fit in a u64
+pub(crate) const MAX_COUNTED_PAGES: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Ordered {
+    pub(crate) order: Vec<u32>,
+    // the first two pages that could have come either way round, if there are any
+    pub(crate) ambiguity: Option<(u32, u32)>,
+}
+
+impl Ordered {
+    pub(crate) fn is_unique(&self) -> bool {
+        self.ambiguity.is_none()
+    }
+}
+
+// pages that each have to come before the next, and the last before the first, starting with the smallest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cycle(pub(crate) Vec<u32>);
+
+impl Cycle {
+    // every page left over once nothing else can be ordered is still waiting for one of the others, so walking
+    // back through pages it's waiting for has to come round to one it's already seen
+    fn find(numbers: &[u32], predecessors: &[Vec<usize>], waiting_for: &[usize]) -> Self {
+        let stuck = |i: &usize| waiting_for[*i] > 0;
+        let mut current = (0..numbers.len()).filter(stuck).min_by_key(|i| numbers[*i]).unwrap();
+        let mut path = Vec::new();
+        while !path.contains(&current) {
+            path.push(current);
+            current = *predecessors[current].iter().filter(|i| stuck(i)).min_by_key(|i| numbers[**i]).unwrap();
+        }
+        let start = path.iter().position(|i| *i == current).unwrap();
+        let mut cycle = path[start..].iter().rev().map(|i| numbers[*i]).collect::<Vec<_>>();
+        let smallest = (0..cycle.len()).min_by_key(|i| cycle[*i]).unwrap();
+        cycle.rotate_left(smallest);
+        Self(cycle)
+    }
+
+    pub(crate) fn rules(&self) -> Vec<Rule> {
+        (0..self.0.len())
+            .map(|i| Rule {
+                left: self.0[i],
+                right: self.0[(i + 1) % self.0.len()],
+            })
+            .collect()
+    }
+}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rules = self.rules().iter().map(|r| format!("{}|{}", r.left, r.right)).collect::<Vec<_>>();
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+// a rule the update breaks, by putting its right page before its left one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Violation {
+    pub(crate) rule: Rule,
+    // where each of the rule's pages is in the update, counting from 0
+    pub(crate) left_position: usize,
+    pub(crate) right_position: usize,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}: {} is page {} but {} is page {}",
+            self.rule.left,
+            self.rule.right,
+            self.rule.left,
+            self.left_position + 1,
+            self.rule.right,
+            self.right_position + 1
+        )
+    }
+}
+
+// one step of turning an update into its corrected order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Change {
+    Keep(u32),
+    Remove(u32),
+    Insert(u32),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Keep(page) => write!(f, "{page}"),
+            Change::Remove(page) => write!(f, "-{page}"),
+            Change::Insert(page) => write!(f, "+{page}"),
+        }
+    }
+}
+
+// why an update is out of order, and the least that has to change to put it right
+#[derive(Debug, Clone)]
+pub(crate) struct Diagnosis {
+    pub(crate) violations: Vec<Violation>,
+    // of all the orders that break none of the rules, one that's reached by moving the fewest pages
+    pub(crate) corrected: std::result::Result<Vec<u32>, Cycle>,
+    // the fewest pages that have to be taken out and put back somewhere else to break none of the rules, in the
+    // order they appear in the update
+    pub(crate) moved: Vec<u32>,
+    // the update with the moved pages removed and put back, empty if there's no corrected order
+    pub(crate) diff: Vec<Change>,
+}
+
+impl Diagnosis {
+    pub(crate) fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl Display for Diagnosis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for violation in self.violations.iter() {
+            writeln!(f, "breaks {violation}")?;
+        }
+        match &self.corrected {
+            Ok(_) => {
+                let moved = self.moved.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                let diff = self.diff.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                writeln!(f, "move {}: {}", moved.join(", "), diff.join(" "))
+            }
+            Err(cycle) => writeln!(f, "no order fixes it, the rules go round in a circle: {cycle}"),
+        }
+    }
+}
+
+impl Rules {
+    // every rule between two of the update's pages that has them the wrong way round, by where the right page is
+    pub(crate) fn violations(&self, update: &[u32]) -> Vec<Violation> {
+        let mut result = Vec::new();
+        for (right_position, right) in update.iter().enumerate() {
+            for (left_position, left) in update.iter().enumerate().skip(right_position + 1) {
+                if self.check(*left, *right) {
+                    result.push(Violation {
+                        rule: Rule {
+                            left: *left,
+                            right: *right,
+                        },
+                        left_position,
+                        right_position,
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    // the pages that stay put are the most that can, whichever of the orders allowed they end up in, and the
+    // corrected order is the one that keeps them as they are and otherwise takes the smallest page first
+    // pages are told apart by number, so an update that has one more than once is an error
+    pub(crate) fn diagnose(&self, update: &[u32]) -> Result<Diagnosis> {
+        let mut seen = HashSet::new();
+        if let Some(page) = update.iter().find(|p| !seen.insert(**p)) {
+            Err(format!("page {page} is in the update more than once"))?;
+        }
+        let violations = self.violations(update);
+        let mut predecessors = self.predecessors(update);
+        let (corrected, moved, diff) = match order_from(update, &predecessors) {
+            Ok(_) => {
+                let stays = most_in_order(&predecessors);
+                let kept = (0..update.len()).filter(|i| stays[*i]).collect::<Vec<_>>();
+                for pair in kept.windows(2) {
+                    predecessors[pair[1]].push(pair[0]);
+                }
+                let order = order_from(update, &predecessors)
+                    .expect("pages that break no rules between them can always stay in order")
+                    .order;
+                let moved = (0..update.len()).filter(|i| !stays[*i]).map(|i| update[i]).collect();
+                let kept = kept.iter().map(|i| update[*i]).collect::<HashSet<_>>();
+                let diff = diff(update, &order, &kept);
+                (Ok(order), moved, diff)
+            }
+            Err(cycle) => (Err(cycle), Vec::new(), Vec::new()),
+        };
+        Ok(Diagnosis {
+            violations,
+            corrected,
+            moved,
+            diff,
+        })
+    }
+}
+
+// which pages, by index, can stay where they are: the most that don't include two where the rules, directly or
+// through other pages, put the later one first
+// those clashes go one way in the update and the other way in the rules, so they're a partial order, and the
+// pages that stay are its largest antichain, which by Dilworth's and König's theorems comes from a maximum matching
+// between the earlier and later page of each clash
+fn most_in_order(predecessors: &[Vec<usize>]) -> Vec<bool> {
+    let n = predecessors.len();
+    // before[i][j] when page i has to come before page j
+    let mut before = vec![vec![false; n]; n];
+    for (right, lefts) in predecessors.iter().enumerate() {
+        for left in lefts {
+            before[*left][right] = true;
+        }
+    }
+    for k in 0..n {
+        let through = before[k].clone();
+        for row in before.iter_mut().filter(|row| row[k]) {
+            for (to, reached) in row.iter_mut().zip(through.iter()) {
+                *to |= *reached;
+            }
+        }
+    }
+    // clashes[i] are the pages after i that have to come before it
+    let clashes = (0..n)
+        .map(|i| (i + 1..n).filter(|j| before[*j][i]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // matched[j] is the earlier page that j's clash with is in the matching
+    let mut matched = vec![None; n];
+    for i in 0..n {
+        augment(i, &clashes, &mut matched, &mut vec![false; n]);
+    }
+
+    // follow clashes forward and matches back from the earlier pages that aren't matched; the smallest set of pages
+    // covering every clash is the earlier pages not reached and the later ones reached, and the rest stay
+    let mut earlier = vec![true; n];
+    for i in matched.iter().flatten() {
+        earlier[*i] = false;
+    }
+    let mut later = vec![false; n];
+    let mut stack = (0..n).filter(|i| earlier[*i]).collect::<Vec<_>>();
+    while let Some(i) = stack.pop() {
+        for j in clashes[i].iter() {
+            if later[*j] {
+                continue;
+            }
+            later[*j] = true;
+            if let Some(k) = matched[*j] {
+                if !earlier[k] {
+                    earlier[k] = true;
+                    stack.push(k);
+                }
+            }
+        }
+    }
+    (0..n).map(|i| earlier[i] && !later[i]).collect()
+}
+
+// Kuhn's augmenting path step: tries to match page i with one of its clashes, moving other matches along to make room
+fn augment(i: usize, clashes: &[Vec<usize>], matched: &mut [Option<usize>], seen: &mut [bool]) -> bool {
+    for j in clashes[i].iter() {
+        if seen[*j] {
+            continue;
+        }
+        seen[*j] = true;
+        let free = match matched[*j] {
+            None => true,
+            Some(k) => augment(k, clashes, matched, seen),
+        };
+        if free {
+            matched[*j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+fn diff(before: &[u32], after: &[u32], kept: &HashSet<u32>) -> Vec<Change> {
+    let mut result = Vec::with_capacity(before.len() + after.len() - kept.len());
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() || j < after.len() {
+        if i < before.len() && !kept.contains(&before[i]) {
+            result.push(Change::Remove(before[i]));
+            i += 1;
+        } else if j < after.len() && !kept.contains(&after[j]) {
+            result.push(Change::Insert(after[j]));
+            j += 1;
+        } else {
+            // both are kept, and the kept pages are in the same order in both
+            result.push(Change::Keep(before[i]));
+            i += 1;
+            j += 1;
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Sequence(pub(crate) Vec<u32>);
+
+impl Sequence {
+    pub(crate) fn new(v: Vec<u32>) -> Self {
+        Self(v)
+    }
+
+    pub(crate) fn is_valid(&self, rules: &Rules) -> bool {
+        for i in 0..(self.0.len() - 1) {
+            let j = i + 1;
+            let left = self.0[i];
+            let right = self.0[j];
+            if !rules.check(left, right) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+pub(crate) struct Input {
+    pub(crate) rules: Rules,
+    pub(crate) sequences: Vec<Sequence>,
+}
+
+pub(crate) fn read(path: &str) -> Result<Input> {
+    let file_contents = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+    .lines()
+    // parse lines
+    .map(|line| {
+        // ignore empty lines
+        let line = line?;
+        let line = line.trim();
+        Ok(line.to_string())
+    })
+    // break if we have an error
+    .collect::<Result<Vec<_>>>()?;
+
+    let divider_regex = Regex::new(r"^(\d+)\|(\d+)$")?;
+    let sequence_regex = Regex::new(r"^(\d+)(?:,(\d+))*$")?;
+
+    let mut iter = file_contents.into_iter();
+    let rules = iter
+        .by_ref()
+        .take_while(|line| divider_regex.is_match(line))
+        .collect::<Vec<_>>();
+    let sequences = iter
+        .by_ref()
+        .take_while(|line| sequence_regex.is_match(line))
+        .collect::<Vec<_>>();
+    let remainder = iter.collect::<Vec<_>>();
+    if !remainder.is_empty() {
+        Err(format!("unmatched line at end of input: {:?}", remainder))?;
+    }
+
+    let rules = rules
+        .into_iter()
+        .map(|line| {
+            let (_, [left, right]) = divider_regex
+                .captures(&line)
+                .ok_or("shold be impossible, already matched")?
+                .extract();
+            Rule::new(left, right)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let sequences = sequences
+        .into_iter()
+        .map(|line| {
+            line.split(",")
+                .map(|num| Ok(num.trim().parse::<u32>()?))
+                .collect::<Result<Vec<_>>>()
+                .map(Sequence::new)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Input {
+        rules: Rules::new(rules),
+        sequences,
+    })
+}
+
+// what the rules make of one update
+#[derive(Debug, Clone)]
+pub(crate) struct Update {
+    // counting from 1, in the order the updates are given
+    pub(crate) update: usize,
+    pub(crate) pages: Vec<u32>,
+    // breaks none of the rules, which pages with no rule between them can't
+    pub(crate) in_order: bool,
+    pub(crate) ordered: std::result::Result<Ordered, Cycle>,
+    pub(crate) orderings: Option<u64>,
+    // for updates that break any rules
+    pub(crate) diagnosis: Option<Diagnosis>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Analysis {
+    pub(crate) updates: Vec<Update>,
+}
+
+pub(crate) fn analyse(path: &str, max_pages: usize) -> Result<Analysis> {
+    let input = read(path)?;
+    let updates = input
+        .sequences
+        .iter()
+        .enumerate()
+        .map(|(i, sequence)| {
+            let diagnosis = input.rules.diagnose(&sequence.0).map_err(|e| format!("update {}: {}", i + 1, e.0))?;
+            Ok(Update {
+                update: i + 1,
+                pages: sequence.0.clone(),
+                in_order: diagnosis.is_valid(),
+                ordered: input.rules.topological_order(&sequence.0),
+                orderings: input.rules.count_orderings(&sequence.0, max_pages),
+                diagnosis: Some(diagnosis).filter(|d| !d.is_valid()),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Analysis { updates })
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count = |f: &dyn Fn(&Update) -> bool| self.updates.iter().filter(|u| f(u)).count();
+        writeln!(
+            f,
+            "{} updates: {} in order, {} with one order, {} with more than one, {} with a cycle",
+            self.updates.len(),
+            count(&|u| u.in_order),
+            count(&|u| u.ordered.as_ref().is_ok_and(|o| o.is_unique())),
+            count(&|u| u.ordered.as_ref().is_ok_and(|o| !o.is_unique())),
+            count(&|u| u.ordered.is_err())
+        )?;
+        writeln!(f)?;
+        writeln!(f, "{:>6}  {:>5}  {:>8}  {:>12}  {:>6}  notes", "update", "pages", "in order", "orderings", "middle")?;
+        for u in self.updates.iter() {
+            let orderings = u.orderings.map_or("?".to_string(), |n| n.to_string());
+            let (middle, notes) = match &u.ordered {
+                Ok(o) => (
+                    o.order[o.order.len() / 2].to_string(),
+                    o.ambiguity.map_or(String::new(), |(a, b)| format!("{a} and {b} could go either way round")),
+                ),
+                Err(cycle) => ("-".to_string(), format!("cycle {cycle}")),
+            };
+            let in_order = if u.in_order { "yes" } else { "no" };
+            writeln!(f, "{:>6}  {:>5}  {:>8}  {:>12}  {:>6}  {}", u.update, u.pages.len(), in_order, orderings, middle, notes)?;
+        }
+
+        for u in self.updates.iter() {
+            if let Some(diagnosis) = &u.diagnosis {
+                writeln!(f)?;
+                let pages = u.pages.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                writeln!(f, "update {}: {}", u.update, pages.join(","))?;
+                write!(f, "{diagnosis}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(pairs: &[(u32, u32)]) -> Rules {
+        Rules::new(pairs.iter().map(|(left, right)| Rule { left: *left, right: *right }).collect())
+    }
+
+    #[test]
+    pub fn test_topological_order() {
+        let input = read("day05-sample.txt").unwrap();
+        let ordered = input.rules.topological_order(&[97, 13, 75, 29, 47]).unwrap();
+        assert_eq!(ordered, Ordered { order: vec![97, 75, 47, 29, 13], ambiguity: None });
+
+        let chain = rules(&[(1, 2), (2, 3), (1, 4)]);
+        let ordered = chain.topological_order(&[3, 4, 2, 1]).unwrap();
+        assert_eq!(ordered.order, vec![1, 2, 3, 4]);
+        assert_eq!(ordered.ambiguity, Some((2, 4)));
+        // rules about pages that aren't in the update don't count, even to link up two that are
+        assert_eq!(chain.topological_order(&[3, 1]).unwrap().ambiguity, Some((1, 3)));
+        assert!(chain.topological_order(&[3, 2]).unwrap().is_unique());
+    }
+
+    #[test]
+    pub fn test_cycle() {
+        let cyclic = rules(&[(5, 1), (1, 9), (9, 5), (2, 5), (9, 3)]);
+        let cycle = cyclic.topological_order(&[3, 9, 2, 1, 5]).unwrap_err();
+        assert_eq!(cycle, Cycle(vec![1, 9, 5]));
+        assert_eq!(cycle.to_string(), "1|9, 9|5, 5|1");
+        // without 9 there's no cycle
+        assert_eq!(cyclic.topological_order(&[3, 2, 1, 5]).unwrap().order, vec![2, 3, 5, 1]);
+        assert_eq!(rules(&[(4, 4)]).topological_order(&[4]).unwrap_err(), Cycle(vec![4]));
+    }
+
+    #[test]
+    pub fn test_count_orderings() {
+        let chain = rules(&[(1, 2), (2, 3), (1, 4)]);
+        // 4 goes anywhere after 1
+        assert_eq!(chain.count_orderings(&[1, 2, 3, 4], 10), Some(3));
+        assert_eq!(chain.count_orderings(&[1, 2, 3], 10), Some(1));
+        assert_eq!(rules(&[]).count_orderings(&[1, 2, 3, 4, 5], 10), Some(120));
+        assert_eq!(rules(&[]).count_orderings(&[1, 2, 3, 4, 5], 4), None);
+        assert_eq!(rules(&[(1, 2), (2, 1)]).count_orderings(&[1, 2], 10), Some(0));
+    }
+
+    #[test]
+    pub fn test_diagnose() {
+        let input = read("day05-sample.txt").unwrap();
+        let diagnosis = input.rules.diagnose(&[97, 13, 75, 29, 47]).unwrap();
+        let broken = diagnosis.violations.iter().map(|v| (v.rule.left, v.rule.right)).collect::<Vec<_>>();
+        assert_eq!(broken, vec![(75, 13), (29, 13), (47, 13), (47, 29)]);
+        assert_eq!((diagnosis.violations[3].left_position, diagnosis.violations[3].right_position), (4, 3));
+        assert_eq!(diagnosis.violations[3].to_string(), "47|29: 47 is page 5 but 29 is page 4");
+        assert_eq!(diagnosis.moved, vec![13, 29]);
+        use Change::*;
+        assert_eq!(diagnosis.diff, vec![Keep(97), Remove(13), Keep(75), Remove(29), Keep(47), Insert(29), Insert(13)]);
+        assert_eq!(diagnosis.to_string().lines().last().unwrap(), "move 13, 29: 97 -13 75 -29 47 +29 +13");
+        assert!(input.rules.diagnose(&[75, 47, 61, 53, 29]).unwrap().is_valid());
+
+        // one page in the wrong place only needs that one moving, however many rules it breaks
+        let diagnosis = input.rules.diagnose(&[13, 97, 75, 47, 61]).unwrap();
+        assert_eq!(diagnosis.violations.len(), 4);
+        assert_eq!(diagnosis.moved, vec![13]);
+
+        // 3 can go anywhere, so moving 1 after 2 is enough, though the smallest first order would be 2,1,3
+        let diagnosis = rules(&[(2, 1)]).diagnose(&[3, 1, 2]).unwrap();
+        assert_eq!(diagnosis.moved, vec![1]);
+        assert_eq!(diagnosis.corrected.as_ref().unwrap(), &vec![3, 2, 1]);
+        assert_eq!(diagnosis.diff, vec![Keep(3), Remove(1), Keep(2), Insert(1)]);
+
+        let cyclic = rules(&[(1, 2), (2, 1)]).diagnose(&[2, 1]).unwrap();
+        assert!(rules(&[(1, 2)]).diagnose(&[1, 2, 1]).is_err());
+        // no rule between 1 and 3 or 3 and 2 means none is broken, though the puzzle's own check wants one for every pair
+        assert!(rules(&[(1, 2)]).diagnose(&[1, 3, 2]).unwrap().is_valid());
+        assert!(!Sequence::new(vec![1, 3, 2]).is_valid(&rules(&[(1, 2)])));
+        assert_eq!(cyclic.violations.len(), 1);
+        assert!(cyclic.moved.is_empty());
+        assert!(cyclic.to_string().ends_with("the rules go round in a circle: 1|2, 2|1\n"));
+    }
+
+    #[test]
+    pub fn test_sample() {
+        let analysis = analyse("day05-sample.txt", 10).unwrap();
+        let report = analysis.to_string();
+        assert!(report.starts_with("6 updates: 3 in order, 6 with one order, 0 with more than one, 0 with a cycle\n"));
+        assert!(report.contains("     6      5        no             1      47  \n"));
+        assert!(report.contains("\nupdate 4: 75,97,47,61,53\nbreaks 97|75: 97 is page 2 but 75 is page 1\nmove 75: -75 97 +75 47 61 53\n"));
+    }
+}
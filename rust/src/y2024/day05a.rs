@@ -10,8 +10,10 @@ use std::{
 
 use regex::Regex;
 
+use super::day05;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -39,6 +41,12 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<day05::Error> for Error {
+    fn from(value: day05::Error) -> Self {
+        Self(value.0)
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
@@ -78,8 +86,7 @@ fn is_sequence_valid(sequence: &[u32], rules: &HashMap<u32, Vec<&Rule>>) -> bool
     true
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u32> {
+pub(crate) fn do_it(path: &str) -> Result<u32> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -149,6 +156,17 @@ fn do_it(path: &str) -> Result<u32> {
         .sum())
 }
 
+// an update is in order when it breaks none of the rules between its pages, rather than when every page has a
+// rule putting it before each one after it
+pub(crate) fn do_it_by_violations(path: &str) -> Result<u32> {
+    let day05::Input { rules, sequences } = day05::read(path)?;
+    Ok(sequences
+        .iter()
+        .filter(|sequence| rules.violations(&sequence.0).is_empty())
+        .map(|sequence| sequence.0[sequence.0.len() / 2])
+        .sum())
+}
+
 #[cfg(test)]
 mod tests {
     use super::do_it;
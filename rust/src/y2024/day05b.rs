@@ -0,0 +1,167 @@
+use std::{fmt::Debug, num::ParseIntError};
+
+use super::day05::{self, Rules, Sequence};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<day05::Error> for Error {
+    fn from(value: day05::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+// backtracking through the rules from each page in turn, which gives None with no explanation when there's no
+// order to find
+fn new_with_numbers(numbers: &[u32], rules: &Rules) -> Option<Sequence> {
+    let rules = Rules::new_with_restricted_numbers(rules, numbers);
+
+    let mut to_visit = numbers.iter().map(|x| (None, *x)).collect::<Vec<_>>();
+    let mut current = Vec::new();
+    let mut current_seq = Sequence::new(Vec::new());
+    let mut visited = (0..rules.grid.len()).map(|_| false).collect::<Vec<_>>();
+
+    loop {
+        match to_visit.pop() {
+            // we have something to try
+            Some((prev, next)) => {
+                // pop off current while the right hand side of current doesn't match the left hand side of the next rule
+                match prev {
+                    // we had some previous node, pop until our current head matches the previous
+                    Some(prev) => loop {
+                        match current.last() {
+                            Some((_, current_head)) => {
+                                if *current_head != prev {
+                                    current.pop();
+                                    if let Some(number) = current_seq.0.pop() {
+                                        visited[number as usize] = false;
+                                    }
+                                } else {
+                                    break;
+                                }
+                            }
+                            None => break,
+                        };
+                    },
+                    // we didn't have a previous node, that means we're starting a new root attempt, so clear the current one
+                    None => {
+                        current.clear();
+                        current_seq.0.clear();
+                        for number in numbers.iter() {
+                            visited[*number as usize] = false;
+                        }
+                    }
+                };
+
+                // add it to our possible solution
+                current.push((prev, next));
+                current_seq.0.push(next);
+                visited[next as usize] = true;
+
+                // no more things to add
+                if current.len() == numbers.len() {
+                    // success, we're done
+                    if current_seq.is_valid(&rules) {
+                        return Some(current_seq);
+                    }
+                    // not a solution
+                    else {
+                        current.pop();
+                        if let Some(number) = current_seq.0.pop() {
+                            visited[number as usize] = false;
+                        }
+                        continue;
+                    }
+                }
+
+                // there must be at least one more number in this sequence before we can check if we're done
+                // add all possible remaining choices
+                if let Some(possible_choices) = rules.possible_choices(next) {
+                    for next in possible_choices
+                        .iter()
+                        .filter(|next| !visited[next.right as usize])
+                    {
+                        to_visit.push((Some(next.left), next.right));
+                    }
+                }
+            }
+            // we're out of possibilities
+            None => {
+                return None;
+            }
+        };
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u32> {
+    let input = day05::read(path)?;
+    let mut sum = 0;
+    for sequence in input.sequences.iter().filter(|sequence| !sequence.is_valid(&input.rules)) {
+        let ordered = input
+            .rules
+            .topological_order(&sequence.0)
+            .map_err(|cycle| format!("no order for {:?}, the rules go round in a circle: {cycle}", sequence.0))?;
+        sum += ordered.order[ordered.order.len() / 2];
+    }
+    Ok(sum)
+}
+
+pub(crate) fn do_it_by_backtracking(path: &str) -> Result<u32> {
+    let day05::Input { rules, sequences } = day05::read(path)?;
+    Ok(sequences
+        .iter()
+        .filter(|sequence| !sequence.is_valid(&rules))
+        .map(|sequence| new_with_numbers(&sequence.0, &rules))
+        .collect::<Option<Vec<_>>>()
+        .ok_or("failed to find a valid ordering for at least one sequence")?
+        .iter()
+        .map(|sequence| sequence.0[sequence.0.len() / 2])
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day05-sample.txt").unwrap(), 123);
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day05.txt").unwrap(), 6142);
+    }
+}
@@ -9,8 +9,10 @@ use std::{
     path::Path,
 };
 
+use crate::{bit_grid::BitGrid, simulation::Simulation};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -61,7 +63,7 @@ impl Add for Point {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Up,
     Down,
@@ -70,7 +72,7 @@ enum Direction {
 }
 
 impl Direction {
-    fn to_vector(&self) -> Point {
+    fn to_vector(self) -> Point {
         match self {
             Direction::Up => Point { x: 0, y: -1 },
             Direction::Down => Point { x: 0, y: 1 },
@@ -89,6 +91,7 @@ impl Direction {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Guard {
     position: Point,
     direction: Direction,
@@ -99,7 +102,7 @@ struct State {
     height: usize,
     data: Vec<bool>,
     guard: Guard,
-    visited: Vec<bool>,
+    visited: BitGrid,
 }
 
 impl State {
@@ -177,7 +180,7 @@ impl State {
                     height,
                     data,
                     guard,
-                    visited: (0..(width * height)).map(|_| false).collect(),
+                    visited: BitGrid::new(width, height),
                 };
                 result.visit(initial_position);
                 Ok(result)
@@ -207,7 +210,7 @@ impl State {
 
     fn visit(&mut self, p: Point) {
         if self.contains_point(p) {
-            self.visited[(p.y as usize) * self.width + (p.x as usize)] = true;
+            self.visited.set(p.x as usize, p.y as usize);
         }
     }
 
@@ -222,6 +225,29 @@ impl State {
     }
 }
 
+impl Simulation for State {
+    type Snapshot = (Guard, BitGrid);
+    type Error = Error;
+
+    fn step(&mut self) -> Result<()> {
+        self.advance();
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        !self.guard_is_still_in_bounds()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.guard, self.visited.clone())
+    }
+
+    fn restore(&mut self, (guard, visited): &Self::Snapshot) {
+        self.guard = *guard;
+        self.visited = visited.clone();
+    }
+}
+
 impl Display for State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.height {
@@ -249,8 +275,7 @@ impl Display for State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<usize> {
+pub(crate) fn do_it(path: &str) -> Result<usize> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -269,23 +294,77 @@ fn do_it(path: &str) -> Result<usize> {
     .collect::<Result<Vec<_>>>()?;
 
     let mut state = State::new(&file_contents)?;
+    crate::alloc_profile::parsed();
 
-    while state.guard_is_still_in_bounds() {
-        state.advance();
-    }
+    state.run(None)?;
 
-    Ok(state.visited.iter().filter(|x| **x).count())
+    Ok(state.visited.count_ones())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::do_it;
+    use super::{do_it, State};
+    use crate::{
+        simulation::{Recorder, Simulation},
+        snapshot::Snapshot,
+    };
+
+    fn sample() -> State {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../puzzle-inputs/day06-sample.txt");
+        let lines = std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| line.trim().to_string())
+            .collect::<Vec<_>>();
+        State::new(&lines).unwrap()
+    }
 
     #[test]
     pub fn test_sample() {
         assert_eq!(do_it("day06-sample.txt").unwrap(), 41);
     }
 
+    #[test]
+    pub fn test_sample_snapshots() {
+        let mut state = sample();
+        let mut snapshot = Snapshot::new("day06a-sample");
+        snapshot.record("step 0", &state);
+        let mut steps = 0;
+        while state.guard_is_still_in_bounds() {
+            state.advance();
+            steps += 1;
+            if steps % 10 == 0 {
+                snapshot.record(format!("step {steps}"), &state);
+            }
+        }
+        snapshot.record(format!("left after step {steps}"), &state);
+        snapshot.check();
+    }
+
+    #[test]
+    pub fn test_sample_rewind() {
+        let mut recorder = Recorder::new(sample(), 8);
+        let steps = recorder.run(None).unwrap();
+        assert_eq!(recorder.simulation().visited.count_ones(), 41);
+
+        let first = recorder.bisect(|state| state.visited.count_ones() >= 20).unwrap().unwrap();
+        let mut state = sample();
+        state.run(Some(first - 1)).unwrap();
+        assert!(state.visited.count_ones() < 20);
+        state.run(Some(1)).unwrap();
+        assert_eq!(state.visited.count_ones(), 20);
+        assert_eq!(recorder.simulation().to_string(), state.to_string());
+
+        recorder.rewind(steps - 1).unwrap();
+        assert!(!recorder.simulation().is_finished());
+        recorder.rewind(3).unwrap();
+        assert_eq!(recorder.simulation().to_string(), {
+            let mut state = sample();
+            state.run(Some(3)).unwrap();
+            state.to_string()
+        });
+    }
+
     #[test]
     pub fn test_real() {
         assert_eq!(do_it("day06.txt").unwrap(), 5208);
@@ -10,7 +10,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -275,8 +275,7 @@ impl Display for State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<usize> {
+pub(crate) fn do_it(path: &str) -> Result<usize> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -295,25 +294,29 @@ fn do_it(path: &str) -> Result<usize> {
     .collect::<Result<Vec<_>>>()?;
 
     let state = State::new(&file_contents)?;
+    crate::alloc_profile::parsed();
 
     let (_, path) = state.find_path();
 
-    Ok(HashSet::<Point>::from_iter(
+    let obstacles = HashSet::<Point>::from_iter(
         path.iter()
             .map(|previous_guard| previous_guard.position + previous_guard.direction.to_vector()),
-    )
-    .iter()
-    .filter_map(|obstacle| {
-        let mut state = state.clone();
-        state.add_obstacle(*obstacle);
-        let (is_infinite, _) = state.find_path();
-        if is_infinite {
-            Some(obstacle)
-        } else {
-            None
-        }
-    })
-    .count())
+    );
+    Ok(obstacles
+        .iter()
+        .enumerate()
+        .filter_map(|(i, obstacle)| {
+            crate::progress::report(i as u64, Some(obstacles.len() as u64), "trying obstacles");
+            let mut state = state.clone();
+            state.add_obstacle(*obstacle);
+            let (is_infinite, _) = state.find_path();
+            if is_infinite {
+                Some(obstacle)
+            } else {
+                None
+            }
+        })
+        .count())
 }
 
 #[cfg(test)]
@@ -8,8 +8,10 @@ use std::{
     path::Path,
 };
 
+use crate::checked::{self, Wide};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -37,6 +39,12 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
@@ -45,8 +53,8 @@ impl From<ParseIntError> for Error {
 
 #[derive(Debug)]
 struct Line {
-    answer: u64,
-    values: Vec<u64>,
+    answer: Wide<u64>,
+    values: Vec<Wide<u64>>,
 }
 
 #[derive(Debug)]
@@ -84,7 +92,7 @@ impl Line {
     fn is_solvable(&self) -> Result<bool> {
         let mut operators = Operators::new(self)?;
         for _ in 0..2u32.pow((self.values.len() - 1) as u32) {
-            if self.is_solution(&operators) {
+            if self.is_solution(&operators)? {
                 return Ok(true);
             }
             operators.next();
@@ -92,20 +100,20 @@ impl Line {
         Ok(false)
     }
 
-    fn is_solution(&self, operators: &Operators) -> bool {
+    fn is_solution(&self, operators: &Operators) -> Result<bool> {
         let mut result = self.values[0];
         for i in 1..self.values.len() {
             let left = result;
             let right = self.values[i];
             result = match operators[i - 1] {
-                Operator::Add => left + right,
-                Operator::Multiply => left * right,
+                Operator::Add => checked::add("day07a", left, right)?,
+                Operator::Multiply => checked::mul("day07a", left, right)?,
             };
             if result > self.answer {
-                return false;
+                return Ok(false);
             }
         }
-        result == self.answer
+        Ok(result == self.answer)
     }
 }
 
@@ -140,8 +148,7 @@ impl Index<usize> for Operators {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<Wide<u64>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -164,12 +171,11 @@ fn do_it(path: &str) -> Result<u64> {
         .map(|line| Line::new(line))
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(lines
+    let answers = lines
         .iter()
         .map(|line| Ok(if line.is_solvable()? { line.answer } else { 0 }))
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(checked::sum("day07a", answers)?)
 }
 
 #[cfg(test)]
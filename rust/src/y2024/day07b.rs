@@ -8,8 +8,10 @@ use std::{
     path::Path,
 };
 
+use crate::checked::{self, Wide};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -37,6 +39,12 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
@@ -45,8 +53,8 @@ impl From<ParseIntError> for Error {
 
 #[derive(Debug)]
 struct Line {
-    answer: u64,
-    values: Vec<u64>,
+    answer: Wide<u64>,
+    values: Vec<Wide<u64>>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -99,13 +107,9 @@ impl Line {
             let left = result;
             let right = self.values[i];
             result = match operators[i - 1] {
-                Operator::Add => left + right,
-                Operator::Multiply => left * right,
-                Operator::Concat => {
-                    let left = left.to_string();
-                    let right = right.to_string();
-                    (left + &right).parse()?
-                }
+                Operator::Add => checked::add("day07b", left, right)?,
+                Operator::Multiply => checked::mul("day07b", left, right)?,
+                Operator::Concat => checked::concat("day07b", left, right)?,
             };
             if result > self.answer {
                 return Ok(false);
@@ -150,8 +154,7 @@ impl Index<usize> for Operators {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<Wide<u64>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -174,12 +177,11 @@ fn do_it(path: &str) -> Result<u64> {
         .map(|line| Line::new(line))
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(lines
+    let answers = lines
         .iter()
         .map(|line| Ok(if line.is_solvable()? { line.answer } else { 0 }))
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(checked::sum("day07b", answers)?)
 }
 
 #[cfg(test)]
@@ -10,7 +10,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -146,8 +146,7 @@ impl Grid {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<usize> {
+pub(crate) fn do_it(path: &str) -> Result<usize> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -8,7 +8,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -49,8 +49,13 @@ struct PuzzleFile {
     len: u64,
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+#[derive(Debug)]
+struct Gap {
+    position: u64,
+    len: u64,
+}
+
+fn parse(path: &str) -> Result<Vec<PuzzleFile>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -96,6 +101,13 @@ fn do_it(path: &str) -> Result<u64> {
         next_position += size;
     }
 
+    Ok(files)
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u64> {
+    let files = parse(path)?;
+    let next_position = files.last().map_or(0, |f| f.position + f.len);
+
     let mut blocks = (0..next_position).map(|_| None).collect::<Vec<_>>();
     for f in files.iter() {
         for i in f.position..(f.position + f.len) {
@@ -126,13 +138,57 @@ fn do_it(path: &str) -> Result<u64> {
         .sum())
 }
 
+// part 2's way of compacting, moving each file to the first gap that fits, but with every block as a file of
+// its own, which has to come out the same as moving blocks
+pub(crate) fn do_it_by_file_moves(path: &str) -> Result<u64> {
+    let files = parse(path)?;
+    let mut gaps = files
+        .windows(2)
+        .map(|pair| Gap {
+            position: pair[0].position + pair[0].len,
+            len: pair[1].position - pair[0].position - pair[0].len,
+        })
+        .filter(|gap| gap.len > 0)
+        .collect::<Vec<_>>();
+    let mut blocks = files
+        .iter()
+        .flat_map(|f| {
+            (f.position..(f.position + f.len)).map(|position| PuzzleFile {
+                index: f.index,
+                position,
+                len: 1,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // gaps are in order and fill up from the left, so everything before first_gap is full
+    let mut first_gap = 0;
+    for block in blocks.iter_mut().rev() {
+        while first_gap < gaps.len() && gaps[first_gap].len == 0 {
+            first_gap += 1;
+        }
+        if let Some(gap) = gaps[first_gap..]
+            .iter_mut()
+            .take_while(|gap| gap.position < block.position)
+            .find(|gap| gap.len >= block.len)
+        {
+            block.position = gap.position;
+            gap.position += block.len;
+            gap.len -= block.len;
+        }
+    }
+
+    Ok(blocks.iter().map(|block| block.position * block.index).sum())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::do_it;
+    use super::{do_it, do_it_by_file_moves};
 
     #[test]
     pub fn test_sample() {
         assert_eq!(do_it("day09-sample.txt").unwrap(), 1928);
+        assert_eq!(do_it_by_file_moves("day09-sample.txt").unwrap(), 1928);
     }
 
     #[test]
@@ -8,7 +8,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -55,8 +55,7 @@ struct Gap {
     len: u64,
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
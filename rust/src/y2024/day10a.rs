@@ -9,7 +9,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -188,8 +188,7 @@ impl Map {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u32> {
+pub(crate) fn do_it(path: &str) -> Result<u32> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -1,5 +1,4 @@
 use std::{
-    collections::HashMap,
     env,
     fmt::Debug,
     fs::File,
@@ -10,8 +9,13 @@ use std::{
     str::Utf8Error,
 };
 
+use crate::{
+    checked::{self, Wide},
+    fast_map::FastHashMap,
+};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -39,6 +43,12 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<checked::Overflow> for Error {
+    fn from(value: checked::Overflow) -> Self {
+        Self(value.to_string())
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
@@ -53,24 +63,24 @@ impl From<Utf8Error> for Error {
 
 struct List {
     // keys are numbers, values are number of times that number appears
-    numbers: HashMap<u64, u64>,
-    next: HashMap<u64, u64>,
+    numbers: FastHashMap<Wide<u64>, Wide<u64>>,
+    next: FastHashMap<Wide<u64>, Wide<u64>>,
 }
 
 impl List {
     fn new(line: &str) -> Result<List> {
-        let mut numbers = HashMap::new();
+        let mut numbers = FastHashMap::default();
         for number in line
             .split(" ")
             .map(|x| Ok(x.parse()?))
             .collect::<Result<Vec<_>>>()?
         {
-            List::increment(&mut numbers, number, 1);
+            List::increment(&mut numbers, number, 1)?;
         }
 
         Ok(List {
             numbers,
-            next: HashMap::new(),
+            next: FastHashMap::default(),
         })
     }
 
@@ -79,7 +89,7 @@ impl List {
 
         for (number, count) in self.numbers.iter() {
             if *number == 0 {
-                List::increment(&mut self.next, 1, *count);
+                List::increment(&mut self.next, 1, *count)?;
             } else {
                 let s = number.to_string();
                 let b = s.as_bytes();
@@ -88,10 +98,10 @@ impl List {
                     let second_half = &b[(b.len() / 2)..];
                     let first_half = std::str::from_utf8(first_half)?;
                     let second_half = std::str::from_utf8(second_half)?;
-                    List::increment(&mut self.next, first_half.parse()?, *count);
-                    List::increment(&mut self.next, second_half.parse()?, *count);
+                    List::increment(&mut self.next, first_half.parse()?, *count)?;
+                    List::increment(&mut self.next, second_half.parse()?, *count)?;
                 } else {
-                    List::increment(&mut self.next, number * 2024, *count);
+                    List::increment(&mut self.next, checked::mul("day11b", *number, 2024)?, *count)?;
                 }
             }
         }
@@ -101,20 +111,18 @@ impl List {
         Ok(())
     }
 
-    fn len(&self) -> u64 {
-        self.numbers.values().sum()
+    fn len(&self) -> Result<Wide<u64>> {
+        Ok(checked::sum("day11b", self.numbers.values().copied())?)
     }
 
-    fn increment(counts: &mut HashMap<u64, u64>, number: u64, times: u64) {
-        counts
-            .entry(number)
-            .and_modify(|existing| *existing += times)
-            .or_insert(times);
+    fn increment(counts: &mut FastHashMap<Wide<u64>, Wide<u64>>, number: Wide<u64>, times: Wide<u64>) -> Result<()> {
+        let existing = counts.entry(number).or_insert(0);
+        *existing = checked::add("day11b", *existing, times)?;
+        Ok(())
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<Wide<u64>> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -140,7 +148,7 @@ fn do_it(path: &str) -> Result<u64> {
     for _ in 0..75 {
         list.advance()?;
     }
-    Ok(list.len())
+    list.len()
 }
 
 #[cfg(test)]
@@ -9,8 +9,10 @@ use std::{
     str::Utf8Error,
 };
 
+use crate::bit_grid::BitGrid;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -81,15 +83,13 @@ impl Map {
     }
 
     fn solve(&self) -> u64 {
-        let mut visited = (0..(self.width * self.height))
-            .map(|_| false)
-            .collect::<Vec<_>>();
+        let mut visited = BitGrid::new(self.width, self.height);
 
         let mut result = 0;
         let mut i = 0;
         for y in 0..(self.height) {
             for x in 0..(self.width) {
-                if !visited[i] {
+                if !visited.get_at(i) {
                     let (child_area, child_perimeter) = self.visit(Point { x, y }, &mut visited);
                     result += child_area * child_perimeter;
                 }
@@ -99,9 +99,9 @@ impl Map {
         result
     }
 
-    fn visit(&self, point: Point, visited: &mut Vec<bool>) -> (u64, u64) {
+    fn visit(&self, point: Point, visited: &mut BitGrid) -> (u64, u64) {
         let i = point.y * self.width + point.x;
-        visited[i] = true;
+        visited.set_at(i);
 
         let this_symbol = self.data[i];
 
@@ -150,7 +150,7 @@ impl Map {
             let other_i = neighbor.y * self.width + neighbor.x;
             let other_symbol = self.data[other_i];
             if this_symbol == other_symbol {
-                if !visited[other_i] {
+                if !visited.get_at(other_i) {
                     let (child_area, child_perimeter) = self.visit(neighbor, visited);
                     area += child_area;
                     perimeter += child_perimeter;
@@ -164,8 +164,7 @@ impl Map {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -11,7 +11,7 @@ use std::{
 use regex::Regex;
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -51,8 +51,7 @@ impl From<Utf8Error> for Error {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<i64> {
+pub(crate) fn do_it(path: &str) -> Result<i64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -0,0 +1,274 @@
+use std::{
+    env,
+    fmt::Debug,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::ParseIntError,
+    path::Path,
+    str::Utf8Error,
+};
+
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(value: Utf8Error) -> Self {
+        Self(format!("core::str::error::Utf8Error({value:?})"))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Machine {
+    button_a_x: i64,
+    button_a_y: i64,
+    button_b_x: i64,
+    button_b_y: i64,
+    prize_x: i64,
+    prize_y: i64,
+}
+
+fn parse(path: &str) -> Result<Vec<Machine>> {
+    let file_contents = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+    .lines()
+    // parse lines
+    .map(|line| {
+        let line = line?;
+        let line = line.trim();
+        Ok(line.to_string())
+    })
+    // break if we have an error
+    .collect::<Result<Vec<_>>>()?;
+
+    // ignore empty lines
+    let file_contents = file_contents
+        .iter()
+        .filter_map(|line| if line.is_empty() { None } else { Some(line) })
+        .collect::<Vec<_>>();
+    if file_contents.len() % 3 != 0 {
+        Err(format!("expected 3 lines per machine, got {} lines", file_contents.len()))?;
+    }
+
+    let button_a_regex = Regex::new(r"^Button A: X\+([0-9]+), Y\+([0-9]+)$")?;
+    let button_b_regex = Regex::new(r"^Button B: X\+([0-9]+), Y\+([0-9]+)$")?;
+    let prize_regex = Regex::new(r"^Prize: X=([0-9]+), Y=([0-9]+)$")?;
+    let mut result = Vec::new();
+    for i in (0..file_contents.len()).step_by(3) {
+        let button_a_string = &file_contents[i];
+        let button_b_string = &file_contents[i + 1];
+        let prize_string = &file_contents[i + 2];
+
+        let (_, [button_a_x, button_a_y]) = button_a_regex
+            .captures(button_a_string)
+            .ok_or_else(|| format!("expected button A string: {}", button_a_string))?
+            .extract();
+        let (_, [button_b_x, button_b_y]) = button_b_regex
+            .captures(button_b_string)
+            .ok_or_else(|| format!("expected button B string: {}", button_b_string))?
+            .extract();
+        let (_, [prize_x, prize_y]) = prize_regex
+            .captures(prize_string)
+            .ok_or_else(|| format!("expected prize string: {}", prize_string))?
+            .extract();
+
+        result.push(Machine {
+            button_a_x: button_a_x.parse::<i64>()?,
+            button_a_y: button_a_y.parse::<i64>()?,
+            button_b_x: button_b_x.parse::<i64>()?,
+            button_b_y: button_b_y.parse::<i64>()?,
+            prize_x: prize_x.parse::<i64>()? + 10000000000000,
+            prize_y: prize_y.parse::<i64>()? + 10000000000000,
+        });
+    }
+    Ok(result)
+}
+
+fn min_tokens_by_search(machine: &Machine) -> Option<i64> {
+    let Machine {
+        button_a_x,
+        button_a_y,
+        button_b_x,
+        button_b_y,
+        prize_x,
+        prize_y,
+    } = *machine;
+    /*
+    it's just linear systems
+
+    A*Ax + B*Bx = Px
+    A*Ay + B*By = Py
+    T = 3*A + B
+
+    A*Ax + B*Bx = Px
+    A = (Px - B*Bx)/Ax
+
+    B = (Py - Px/Ax*Ay)/(By - Bx/Ax*Ay)
+
+    substitue B back into the equation for A using the other axis, y
+    A*Ay + B*By = Py
+    A = (Py - B*By)/Ay
+    */
+    let b = ((prize_y as f64) - (prize_x as f64) / (button_a_x as f64) * (button_a_y as f64))
+        / ((button_b_y as f64)
+            - (button_b_x as f64) / (button_a_x as f64) * (button_a_y as f64));
+    let a = ((prize_y as f64) - b * (button_b_y as f64)) / (button_a_y as f64);
+
+    let a_guess = a.floor() as i64;
+    let initial_a = a_guess - 50;
+    let max_a = a_guess + 50;
+
+    let mut min_tokens: Option<i64> = None;
+    for a in initial_a..=max_a {
+        let b_x = {
+            let numerator = prize_x - a * button_a_x;
+            if numerator % button_b_x == 0 {
+                Some(numerator / button_b_x)
+            } else {
+                None
+            }
+        };
+        let b_y = {
+            let numerator = prize_y - a * button_a_y;
+            if numerator % button_b_y == 0 {
+                Some(numerator / button_b_y)
+            } else {
+                None
+            }
+        };
+        match (b_x, b_y) {
+            // all rules match, and neither button is pressed a negative number of times
+            (Some(b_x), Some(b_y)) if b_x == b_y && b_x >= 0 && a >= 0 => {
+                let t = a * 3 + b_x;
+                // keep only if it's smaller than the current vlaue
+                min_tokens = Some(if let Some(existing) = min_tokens {
+                    existing.min(t)
+                } else {
+                    t
+                });
+            }
+            // didn't match the rules, skip this one
+            (_, _) => (),
+        }
+    }
+    min_tokens
+}
+
+// solves the two equations in integers, so there's nothing to search
+fn min_tokens_exact(machine: &Machine) -> Option<i64> {
+    let (ax, ay) = (machine.button_a_x as i128, machine.button_a_y as i128);
+    let (bx, by) = (machine.button_b_x as i128, machine.button_b_y as i128);
+    let (px, py) = (machine.prize_x as i128, machine.prize_y as i128);
+
+    // Cramer's rule, when the buttons move the claw in different directions there's only one answer
+    let determinant = ax * by - ay * bx;
+    if determinant != 0 {
+        let a = px * by - py * bx;
+        let b = ax * py - ay * px;
+        if a % determinant != 0 || b % determinant != 0 {
+            return None;
+        }
+        let (a, b) = (a / determinant, b / determinant);
+        return (a >= 0 && b >= 0).then(|| (3 * a + b) as i64);
+    }
+
+    /*
+    otherwise both buttons move along the same line, so the prize has to be on it too
+    and then it's one equation, a*u + b*v = w, in whichever axis isn't always zero
+    */
+    let on_line = |x: i128, y: i128| x * py == y * px;
+    if !(on_line(ax, ay) && on_line(bx, by)) {
+        return None;
+    }
+    let (u, v, w) = if ax != 0 || bx != 0 { (ax, bx, px) } else { (ay, by, py) };
+    if u == 0 && v == 0 {
+        return (w == 0).then_some(0);
+    }
+    if u == 0 || v == 0 {
+        let (presses, cost) = if u == 0 { (v, 1) } else { (u, 3) };
+        return (w % presses == 0 && w / presses >= 0).then(|| (cost * w / presses) as i64);
+    }
+
+    // every answer is a = a0 + k*v/g, b = b0 - k*u/g for some k
+    let (g, x, y) = extended_gcd(u, v);
+    if w % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x * (w / g), y * (w / g));
+    let (step_a, step_b) = (v / g, u / g);
+    // the range of k that keeps both presses non-negative
+    let low = (-a0).div_euclid(step_a) + if (-a0).rem_euclid(step_a) == 0 { 0 } else { 1 };
+    let high = b0.div_euclid(step_b);
+    if low > high {
+        return None;
+    }
+    // the cost 3a + b changes by 3*step_a - step_b for each k, so the cheapest is at one end
+    let k = if 3 * step_a > step_b { low } else { high };
+    Some((3 * (a0 + k * step_a) + (b0 - k * step_b)) as i64)
+}
+
+// g = gcd(a, b) along with x and y where a*x + b*y = g, for positive a and b
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<i64> {
+    Ok(parse(path)?.iter().filter_map(min_tokens_by_search).sum())
+}
+
+pub(crate) fn do_it_exact(path: &str) -> Result<i64> {
+    Ok(parse(path)?.iter().filter_map(min_tokens_exact).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{do_it, do_it_exact};
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day13.txt").unwrap(), 74478585072604);
+        assert_eq!(do_it_exact("day13.txt").unwrap(), 74478585072604);
+    }
+}
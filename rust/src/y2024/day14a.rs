@@ -11,8 +11,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -52,7 +54,7 @@ impl From<Utf8Error> for Error {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Point {
     x: i64,
     y: i64,
@@ -123,8 +125,32 @@ impl State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
+// the robots never stop, so it's up to the caller how long to run for
+impl Simulation for State {
+    type Snapshot = Vec<Point>;
+    type Error = Error;
+
+    fn step(&mut self) -> Result<()> {
+        self.advance();
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.robots.iter().map(|r| r.position).collect()
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        for (r, position) in self.robots.iter_mut().zip(snapshot.iter()) {
+            r.position = *position;
+        }
+    }
+}
+
+pub(crate) fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -169,9 +195,7 @@ fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
             .collect::<Result<Vec<_>>>()?,
     };
 
-    for _ in 0..100 {
-        state.advance();
-    }
+    state.run(Some(100))?;
 
     Ok(state.count())
 }
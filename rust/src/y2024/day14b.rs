@@ -11,8 +11,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::bit_grid::BitGrid;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -105,18 +107,15 @@ impl State {
 
     fn count_max_contiguous(&self) -> u64 {
         let grid = self.create_2d_grid();
-        let mut visited = (0..self.height)
-            .map(|_| (0..self.width).map(|_| false).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+        let mut visited = BitGrid::new(self.width as usize, self.height as usize);
         fn visit(
             p: Point,
             width: i64,
             height: i64,
-            grid: &Vec<Vec<bool>>,
-            visited: &mut Vec<Vec<bool>>,
+            grid: &BitGrid,
+            visited: &mut BitGrid,
         ) -> u64 {
-            if grid[p.y as usize][p.x as usize] && !visited[p.y as usize][p.x as usize] {
-                visited[p.y as usize][p.x as usize] = true;
+            if grid.get(p.x as usize, p.y as usize) && visited.insert(p.x as usize, p.y as usize) {
                 let mut result = 1;
                 if p.x >= 1 {
                     result += visit(Point { x: p.x - 1, y: p.y }, width, height, grid, visited);
@@ -200,7 +199,7 @@ impl State {
         let grid = self.create_2d_grid();
         for y in 0..(self.height as usize) {
             for x in 0..(self.width as usize) {
-                if grid[y][x] {
+                if grid.get(x, y) {
                     print!("X");
                 } else {
                     print!(" ");
@@ -210,19 +209,16 @@ impl State {
         }
     }
 
-    fn create_2d_grid(&self) -> Vec<Vec<bool>> {
-        let mut result = (0..self.height)
-            .map(|_| (0..self.width).map(|_| false).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+    fn create_2d_grid(&self) -> BitGrid {
+        let mut result = BitGrid::new(self.width as usize, self.height as usize);
         for r in self.robots.iter() {
-            result[r.position.y as usize][r.position.x as usize] = true;
+            result.set(r.position.x as usize, r.position.y as usize);
         }
         result
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
+pub(crate) fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -279,6 +275,10 @@ fn do_it(path: &str, width: usize, height: usize) -> Result<u64> {
     loop {
         state.advance();
         i += 1;
+        // positions repeat after at most width * height steps
+        crate::progress::report_with(i, Some((width * height) as u64), || {
+            format!("best so far {}", solution.as_ref().map_or(0, |s| s.count))
+        });
         let count = state.count_max_contiguous();
         solution = Some(if let Some(solution) = solution {
             if count > solution.count {
@@ -12,8 +12,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -223,8 +225,40 @@ impl State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+// the warehouse, and the moves the robot still has to make
+struct Warehouse {
+    state: State,
+    moves: Vec<Direction>,
+    next_move: usize,
+}
+
+impl Simulation for Warehouse {
+    type Snapshot = (Vec<Cell>, Point, usize);
+    type Error = Error;
+
+    fn step(&mut self) -> Result<()> {
+        let d = *self.moves.get(self.next_move).ok_or("no moves left")?;
+        self.state.advance(d)?;
+        self.next_move += 1;
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.next_move == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.state.state.clone(), self.state.robot_position, self.next_move)
+    }
+
+    fn restore(&mut self, (cells, robot_position, next_move): &Self::Snapshot) {
+        self.state.state.clone_from(cells);
+        self.state.robot_position = *robot_position;
+        self.next_move = *next_move;
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -261,20 +295,31 @@ fn do_it(path: &str) -> Result<u64> {
         }
     }
 
-    let mut state = State::new(map)?;
-
-    for c in instructions.join("").chars() {
-        let d = match c {
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            _ => Err(format!("unparsable direction: {}", c))?,
-        };
-        state.advance(d)?;
-    }
+    let state = State::new(map)?;
+    crate::alloc_profile::parsed();
+
+    let moves = instructions
+        .join("")
+        .chars()
+        .map(|c| {
+            Ok(match c {
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                _ => Err(format!("unparsable direction: {}", c))?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut warehouse = Warehouse {
+        state,
+        moves,
+        next_move: 0,
+    };
+    warehouse.run(None)?;
 
-    Ok(state.count_box_gps())
+    Ok(warehouse.state.count_box_gps())
 }
 
 #[cfg(test)]
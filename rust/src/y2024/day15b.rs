@@ -1,7 +1,7 @@
 use std::{
     collections::HashSet,
     env,
-    fmt::Debug,
+    fmt::{Debug, Display},
     fs::File,
     io::{BufRead, BufReader},
     num::ParseIntError,
@@ -12,8 +12,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -282,8 +284,65 @@ impl State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let p = Point {
+                    x: x as i64,
+                    y: y as i64,
+                };
+                if p == self.robot_position {
+                    write!(f, "@")?;
+                } else {
+                    match self.get(p) {
+                        Cell::Empty => write!(f, ".")?,
+                        Cell::BoxLeft => write!(f, "[")?,
+                        Cell::BoxRight => write!(f, "]")?,
+                        Cell::Wall => write!(f, "#")?,
+                    };
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// the warehouse, and the moves the robot still has to make
+struct Warehouse {
+    state: State,
+    moves: Vec<Direction>,
+    next_move: usize,
+}
+
+impl Simulation for Warehouse {
+    type Snapshot = (Vec<Cell>, Point, usize);
+    type Error = Error;
+
+    fn step(&mut self) -> Result<()> {
+        let d = *self.moves.get(self.next_move).ok_or("no moves left")?;
+        self.state.advance(d)?;
+        self.next_move += 1;
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.next_move == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.state.state.clone(), self.state.robot_position, self.next_move)
+    }
+
+    fn restore(&mut self, (cells, robot_position, next_move): &Self::Snapshot) {
+        self.state.state.clone_from(cells);
+        self.state.robot_position = *robot_position;
+        self.next_move = *next_move;
+    }
+}
+
+fn parse(path: &str) -> Result<(State, Vec<Direction>)> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -320,31 +379,61 @@ fn do_it(path: &str) -> Result<u64> {
         }
     }
 
-    let mut state = State::new(map)?;
+    let state = State::new(map)?;
+
+    let directions = instructions
+        .join("")
+        .chars()
+        .map(|c| {
+            Ok(match c {
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                _ => Err(format!("unparsable direction: {}", c))?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    for c in instructions.join("").chars() {
-        let d = match c {
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            _ => Err(format!("unparsable direction: {}", c))?,
-        };
-        state.advance(d)?;
-    }
+    Ok((state, directions))
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u64> {
+    let (state, moves) = parse(path)?;
+    crate::alloc_profile::parsed();
+
+    let mut warehouse = Warehouse {
+        state,
+        moves,
+        next_move: 0,
+    };
+    warehouse.run(None)?;
 
-    Ok(state.count_box_gps())
+    Ok(warehouse.state.count_box_gps())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::do_it;
+    use super::{do_it, parse};
+    use crate::snapshot::Snapshot;
 
     #[test]
     pub fn test_sample1() {
         assert_eq!(do_it("day15b-sample1.txt").unwrap(), 618);
     }
 
+    #[test]
+    pub fn test_sample1_snapshots() {
+        let (mut state, directions) = parse("day15b-sample1.txt").unwrap();
+        let mut snapshot = Snapshot::new("day15b-sample1");
+        snapshot.record("start", &state);
+        for (i, d) in directions.into_iter().enumerate() {
+            state.advance(d).unwrap();
+            snapshot.record(format!("move {} {:?}", i + 1, d), &state);
+        }
+        snapshot.check();
+    }
+
     #[test]
     pub fn test_sample2() {
         assert_eq!(do_it("day15-sample2.txt").unwrap(), 9021);
@@ -11,8 +11,10 @@ use std::{
     str::Utf8Error,
 };
 
+use crate::bit_grid::BitGrid;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -236,9 +238,8 @@ impl State {
         */
 
         let mut queue = Vec::new();
-        let mut queue_contains = (0..(self.width * self.height * 4))
-            .map(|_| false)
-            .collect::<Vec<_>>();
+        // one bit per graph node, a copy of the maze for each direction stacked on top of each other
+        let mut queue_contains = BitGrid::new(self.width, self.height * 4);
         let mut graph = (0..(self.width * self.height * 4))
             .map(|_| None)
             .collect::<Vec<_>>();
@@ -260,7 +261,7 @@ impl State {
                             direction: d,
                         };
                         queue.push(node);
-                        queue_contains[self.graph_node_index(&node)] = true;
+                        queue_contains.set_at(self.graph_node_index(&node));
                         if d == Direction::Right && p == self.start {
                             graph[self.graph_node_index(&node)] = Some(PathElement::Start);
                         }
@@ -297,13 +298,13 @@ impl State {
                 .ok_or("failed to pop from queue, but it should have at least one thing")?;
             let next = *next;
             queue.swap_remove(next_i);
-            queue_contains[self.graph_node_index(&next)] = false;
+            queue_contains.clear_at(self.graph_node_index(&next));
 
             let current_distance_to_next =
                 self.effective_distance(&graph[self.graph_node_index(&next)]).ok_or("can't possibly have got to a node in the queue without there being some distance to it")?;
 
             self.neighbors(&next, |neighbor, delta| {
-                if queue_contains[self.graph_node_index(&neighbor)] {
+                if queue_contains.get_at(self.graph_node_index(&neighbor)) {
                     let current_distance_to_neighbor =
                         self.effective_distance(&graph[self.graph_node_index(&neighbor)]);
 
@@ -388,8 +389,7 @@ impl State {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
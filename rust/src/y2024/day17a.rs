@@ -10,8 +10,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -58,6 +60,8 @@ struct VM {
     program: Vec<u8>,
     instruction_pointer: usize,
     is_halted: bool,
+    // everything out has written so far, when stepping as a Simulation
+    output: Vec<u8>,
 }
 
 impl VM {
@@ -69,6 +73,7 @@ impl VM {
             program,
             instruction_pointer: 0,
             is_halted: false,
+            output: Vec::new(),
         }
     }
 
@@ -189,8 +194,41 @@ impl VM {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<String> {
+impl Simulation for VM {
+    // registers, instruction pointer, halted, and output so far
+    type Snapshot = (u64, u64, u64, usize, bool, Vec<u8>);
+    type Error = Error;
+
+    fn step(&mut self) -> Result<()> {
+        let mut written = None;
+        VM::step(self, |out| written = Some(out))?;
+        self.output.extend(written);
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_halted
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.a,
+            self.b,
+            self.c,
+            self.instruction_pointer,
+            self.is_halted,
+            self.output.clone(),
+        )
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        (self.a, self.b, self.c, self.instruction_pointer, self.is_halted) =
+            (snapshot.0, snapshot.1, snapshot.2, snapshot.3, snapshot.4);
+        self.output.clone_from(&snapshot.5);
+    }
+}
+
+pub(crate) fn do_it(path: &str) -> Result<String> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -246,11 +284,13 @@ fn do_it(path: &str) -> Result<String> {
             .map(|x| Ok(x.parse()?))
             .collect::<Result<Vec<_>>>()?,
     );
-    let mut output = Vec::new();
-    while !vm.is_halted {
-        vm.step(|out| output.push(out.to_string()))?;
-    }
-    Ok(output.join(","))
+    Simulation::run(&mut vm, None)?;
+    Ok(vm
+        .output
+        .iter()
+        .map(|out| out.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
 }
 
 #[cfg(test)]
@@ -11,7 +11,7 @@ use std::{
 use regex::Regex;
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -207,8 +207,7 @@ impl VM {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
+pub(crate) fn do_it(path: &str) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -286,9 +285,7 @@ fn do_it(path: &str) -> Result<u64> {
                 break;
             }
         }
-        println!("TODO a: {}", a);
-        println!("TODO goal: {:?}", goal);
-        println!("TODO output: {:?}", output);
+        crate::progress::report_with(a, None, || format!("a = {a}, output = {output:?}"));
         if output == goal {
             return Ok(a);
         } else {
@@ -12,8 +12,10 @@ use std::{
 
 use regex::Regex;
 
+use crate::bit_grid::BitGrid;
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -115,7 +117,7 @@ impl Direction {
 struct Grid {
     width: usize,
     height: usize,
-    data: Vec<bool>,
+    data: BitGrid,
 }
 
 #[derive(Debug, Clone)]
@@ -129,7 +131,7 @@ impl Grid {
         let mut result = Self {
             width,
             height,
-            data: (0..(width * height)).map(|_| false).collect::<Vec<_>>(),
+            data: BitGrid::new(width, height),
         };
         let r = Regex::new(r"^([0-9]+),([0-9]+)$")?;
         for line in lines {
@@ -139,7 +141,7 @@ impl Grid {
                 .extract();
             let x: usize = x.parse()?;
             let y: usize = y.parse()?;
-            result.data[y * width + x] = true;
+            result.data.set(x, y);
         }
         Ok(result)
     }
@@ -153,9 +155,7 @@ impl Grid {
         */
 
         let mut queue = Vec::new();
-        let mut queue_contains = (0..(self.width * self.height))
-            .map(|_| false)
-            .collect::<Vec<_>>();
+        let mut queue_contains = BitGrid::new(self.width, self.height);
         let mut graph = (0..(self.width * self.height))
             .map(|_| None)
             .collect::<Vec<_>>();
@@ -166,9 +166,9 @@ impl Grid {
                     y: y as i64,
                 };
                 let p_i = self.index(p)?;
-                if !self.data[p_i] {
+                if !self.data.get_at(p_i) {
                     queue.push(p);
-                    queue_contains[p_i] = true;
+                    queue_contains.set_at(p_i);
                     if p == start {
                         graph[p_i] = Some(PathElement::Start);
                     }
@@ -205,7 +205,7 @@ impl Grid {
             let next = *next;
             queue.swap_remove(next_i);
             let next_i = self.index(next)?;
-            queue_contains[next_i] = false;
+            queue_contains.clear_at(next_i);
 
             let current_distance_to_next =
                 self.effective_distance(&graph[next_i]).ok_or("can't possibly have got to a node in the queue without there being some distance to it")?;
@@ -218,7 +218,7 @@ impl Grid {
             ] {
                 let neighbor = next + d.to_vector();
                 if let Ok(neighbor_i) = self.index(neighbor) {
-                    if queue_contains[neighbor_i] {
+                    if queue_contains.get_at(neighbor_i) {
                         let current_distance_to_neighbor =
                             self.effective_distance(&graph[neighbor_i]);
 
@@ -279,8 +279,7 @@ impl Grid {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str, width: usize, height: usize, count: usize) -> Result<u64> {
+pub(crate) fn do_it(path: &str, width: usize, height: usize, count: usize) -> Result<u64> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -0,0 +1,310 @@
+use std::{
+    collections::VecDeque,
+    env,
+    fmt::Debug,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::ParseIntError,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    path::Path,
+    str::Utf8Error,
+};
+
+use regex::Regex;
+
+use crate::bit_grid::BitGrid;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(value: Utf8Error) -> Self {
+        Self(format!("core::str::error::Utf8Error({value:?})"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl Add<Point> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign<Point> for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl SubAssign<Point> for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+struct Grid {
+    width: usize,
+    height: usize,
+    data: BitGrid,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize, lines: &[String]) -> Result<Grid> {
+        let mut result = Self {
+            width,
+            height,
+            data: BitGrid::new(width, height),
+        };
+        let r = Regex::new(r"^([0-9]+),([0-9]+)$")?;
+        for line in lines {
+            result.add(&r, line)?;
+        }
+        Ok(result)
+    }
+
+    // one more byte falls, and this is where
+    fn add(&mut self, r: &Regex, line: &str) -> Result<(usize, usize)> {
+        let (_, [x, y]) = r
+            .captures(line)
+            .ok_or(format!("regex failed: {line}"))?
+            .extract();
+        let x: usize = x.parse()?;
+        let y: usize = y.parse()?;
+        self.data.set(x, y);
+        Ok((x, y))
+    }
+
+    fn path_exists(&self, start: Point, goal: Point) -> Result<bool> {
+        /*
+        search out from the start, only ever looking next to cells reached for the first time, so every cell is
+        looked at once however far the way out winds, and stop as soon as the goal is reached
+        */
+        let start = self.index(start)?;
+        let goal = self.index(goal)?;
+        if self.data.get_at(start) {
+            return Ok(false);
+        }
+        let mut reached = BitGrid::new(self.width, self.height);
+        reached.set_at(start);
+        let mut frontier = vec![start];
+        while let Some(i) = frontier.pop() {
+            if i == goal {
+                return Ok(true);
+            }
+            let (x, y) = (i % self.width, i / self.width);
+            let neighbours = [
+                (x > 0).then(|| i - 1),
+                (x + 1 < self.width).then(|| i + 1),
+                (y > 0).then(|| i - self.width),
+                (y + 1 < self.height).then(|| i + self.width),
+            ];
+            for next in neighbours.into_iter().flatten() {
+                if !self.data.get_at(next) && reached.insert_at(next) {
+                    frontier.push(next);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    // the cells on a shortest way from start to goal, if there is one, found by breadth first search
+    fn shortest_path(&self, start: Point, goal: Point) -> Result<Option<BitGrid>> {
+        let start = self.index(start)?;
+        let goal = self.index(goal)?;
+        if self.data.get_at(start) {
+            return Ok(None);
+        }
+        let mut previous = vec![None; self.width * self.height];
+        previous[start] = Some(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(i) = queue.pop_front() {
+            if i == goal {
+                let mut path = BitGrid::new(self.width, self.height);
+                let mut i = goal;
+                path.set_at(i);
+                while i != start {
+                    i = previous[i].unwrap();
+                    path.set_at(i);
+                }
+                return Ok(Some(path));
+            }
+            let (x, y) = (i % self.width, i / self.width);
+            let neighbours = [
+                (x > 0).then(|| i - 1),
+                (x + 1 < self.width).then(|| i + 1),
+                (y > 0).then(|| i - self.width),
+                (y + 1 < self.height).then(|| i + self.width),
+            ];
+            for next in neighbours.into_iter().flatten() {
+                if previous[next].is_none() && !self.data.get_at(next) {
+                    previous[next] = Some(i);
+                    queue.push_back(next);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn index(&self, p: Point) -> Result<usize> {
+        if p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height {
+            Ok(p.y as usize * self.width + p.x as usize)
+        } else {
+            Err(format!("out of bounds: {:?}", p))?
+        }
+    }
+}
+
+pub(crate) fn do_it(path: &str, width: usize, height: usize) -> Result<String> {
+    let file_contents = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+    .lines()
+    // parse lines
+    .map(|line| {
+        let line = line?;
+        let line = line.trim();
+        Ok(line.to_string())
+    })
+    // break if we have an error
+    .collect::<Result<Vec<_>>>()?;
+
+    // ignore empty lines
+    let file_contents = file_contents
+        .into_iter()
+        .filter_map(|line| if line.is_empty() { None } else { Some(line) })
+        .collect::<Vec<_>>();
+
+    /*
+    binary search a split point in the list
+    looking for the first point at which the maze becomes unsolvable
+    there's always a way out with floor bytes fallen and never with ceiling
+    */
+    let path_exists = |count: usize| -> Result<bool> {
+        let grid = Grid::new(width, height, &file_contents[0..count])?;
+        grid.path_exists(
+            Point { x: 0, y: 0 },
+            Point {
+                x: (width as i64) - 1,
+                y: (height as i64) - 1,
+            },
+        )
+    };
+    let mut floor = 0;
+    let mut ceiling = file_contents.len();
+    if !path_exists(floor)? || path_exists(ceiling)? {
+        Err("the way out is never cut off, or is from the start")?;
+    }
+    while ceiling - floor > 1 {
+        let count = (floor + ceiling) / 2;
+        if path_exists(count)? {
+            floor = count;
+        } else {
+            ceiling = count;
+        }
+    }
+    // the byte that made the difference
+    Ok(file_contents[ceiling - 1].clone())
+}
+
+// drops the bytes one at a time, looking for a new way out whenever one lands on the current one
+pub(crate) fn do_it_linear(path: &str, width: usize, height: usize) -> Result<String> {
+    let file_contents = BufReader::new(File::open(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("puzzle-inputs")
+            .join(path),
+    )?)
+    .lines()
+    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut grid = Grid::new(width, height, &[])?;
+    let r = Regex::new(r"^([0-9]+),([0-9]+)$")?;
+    let start = Point { x: 0, y: 0 };
+    let goal = Point {
+        x: (width as i64) - 1,
+        y: (height as i64) - 1,
+    };
+    let mut path = grid.shortest_path(start, goal)?;
+    for line in file_contents.iter().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+        let (x, y) = grid.add(&r, line)?;
+        if path.as_ref().is_some_and(|path| path.get(x, y)) {
+            path = grid.shortest_path(start, goal)?;
+        }
+        if path.is_none() {
+            return Ok(line.to_string());
+        }
+    }
+    Err("the way out never gets cut off")?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{do_it, do_it_linear};
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day18-sample.txt", 7, 7).unwrap(), "6,1");
+        assert_eq!(do_it_linear("day18-sample.txt", 7, 7).unwrap(), "6,1");
+    }
+
+    #[test]
+    pub fn test_real() {
+        assert_eq!(do_it("day18.txt", 71, 71).unwrap(), "43,12");
+    }
+}
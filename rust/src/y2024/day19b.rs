@@ -1,5 +1,4 @@
 use std::{
-    collections::{HashMap, HashSet},
     env,
     fmt::Debug,
     fs::File,
@@ -9,8 +8,10 @@ use std::{
     str::Utf8Error,
 };
 
+use crate::fast_map::{FastHashMap, FastHashSet};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -59,8 +60,8 @@ struct Pattern(Vec<char>);
 impl Pattern {
     fn count_possible<'a>(
         &'a self,
-        choices: &HashMap<char, Vec<Towel>>,
-        answers: &mut HashMap<&'a [char], usize>,
+        choices: &FastHashMap<char, Vec<Towel>>,
+        answers: &mut FastHashMap<&'a [char], usize>,
     ) -> usize {
         count_possible(&self.0, choices, answers)
     }
@@ -68,8 +69,8 @@ impl Pattern {
 
 fn count_possible<'a>(
     pattern: &'a [char],
-    choices: &HashMap<char, Vec<Towel>>,
-    answers: &mut HashMap<&'a [char], usize>,
+    choices: &FastHashMap<char, Vec<Towel>>,
+    answers: &mut FastHashMap<&'a [char], usize>,
 ) -> usize {
     /*
     find all the choices that match the start of the pattern
@@ -103,8 +104,7 @@ fn count_possible<'a>(
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<usize> {
+pub(crate) fn do_it(path: &str) -> Result<usize> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -133,11 +133,11 @@ fn do_it(path: &str) -> Result<usize> {
         .collect::<Vec<_>>();
 
     // remove duplicate towels
-    let choices = HashSet::<Towel>::from_iter(choices)
+    let choices = FastHashSet::<Towel>::from_iter(choices)
         .into_iter()
         .collect::<Vec<_>>();
 
-    let mut choices_by_first_latter = HashMap::new();
+    let mut choices_by_first_latter = FastHashMap::default();
     for choice in choices.into_iter() {
         choices_by_first_latter
             .entry(choice.0[0])
@@ -150,7 +150,7 @@ fn do_it(path: &str) -> Result<usize> {
         .map(|pattern| Pattern(pattern.chars().collect::<Vec<_>>()))
         .collect::<Vec<_>>();
 
-    let mut answers = HashMap::new();
+    let mut answers = FastHashMap::default();
 
     Ok(patterns
         .iter()
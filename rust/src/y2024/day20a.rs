@@ -12,7 +12,7 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -364,8 +364,7 @@ impl Grid {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str, at_least_time_saved: u64) -> Result<usize> {
+pub(crate) fn do_it(path: &str, at_least_time_saved: u64) -> Result<usize> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("..")
@@ -1,15 +1,15 @@
 use std::{
     env,
     fmt::Debug,
-    fs::File,
-    io::{BufRead, BufReader},
     num::ParseIntError,
     path::Path,
     str::Utf8Error,
 };
 
+use crate::table::{self, Reader};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -37,6 +37,12 @@ impl From<regex::Error> for Error {
     }
 }
 
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
 impl From<ParseIntError> for Error {
     fn from(value: core::num::ParseIntError) -> Self {
         Self(format!("core::num::ParseIntError({value:?})"))
@@ -59,31 +65,11 @@ fn divide_step(input: u64, arg: u64) -> u64 {
     (input ^ next) % 16777216
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<u64> {
-    let file_contents = BufReader::new(File::open(
-        Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("puzzle-inputs").join(path),
-    )?)
-    .lines()
-    // parse lines
-    .map(|line| {
-        let line = line?;
-        let line = line.trim();
-        Ok(line.to_string())
-    })
-    // break if we have an error
-    .collect::<Result<Vec<_>>>()?;
-
-    // ignore empty lines
-    let file_contents = file_contents
-        .into_iter()
-        .filter_map(|line| if line.is_empty() { None } else { Some(line) })
-        .collect::<Vec<_>>();
-
-    let input = file_contents
-        .iter()
-        .map(|line| Ok(line.parse::<u64>()?))
-        .collect::<Result<Vec<_>>>()?;
+pub(crate) fn do_it(path: &str) -> Result<u64> {
+    let input = Reader::with_columns(1)
+        .read_columns::<u64>(&Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("puzzle-inputs").join(path))?
+        .remove(0);
+    crate::alloc_profile::parsed();
 
     let mut result = 0;
     for number in input {
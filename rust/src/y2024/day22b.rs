@@ -0,0 +1,138 @@
+use std::{
+    env,
+    fmt::Debug,
+    num::ParseIntError,
+    path::Path,
+    str::Utf8Error,
+};
+
+use crate::{
+    fast_map::{DenseMap, Packer},
+    table::{self, Reader},
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Error(#[allow(dead_code)] String);
+
+type Result<T> = std::result::Result<T, Error>;
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self(format!("std::io::Error({value:?})"))
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(value: regex::Error) -> Self {
+        Self(format!("regex::Error({value:?})"))
+    }
+}
+
+impl From<table::Error> for Error {
+    fn from(value: table::Error) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: core::num::ParseIntError) -> Self {
+        Self(format!("core::num::ParseIntError({value:?})"))
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(value: Utf8Error) -> Self {
+        Self(format!("core::str::error::Utf8Error({value:?})"))
+    }
+}
+
+fn multiply_step(input: u64, arg: u64) -> u64 {
+    let next = input * arg;
+    (input ^ next) % 16777216
+}
+
+fn divide_step(input: u64, arg: u64) -> u64 {
+    let next = input / arg;
+    (input ^ next) % 16777216
+}
+
+pub(crate) fn do_it(path: &str) -> Result<u64> {
+    let input = Reader::with_columns(1)
+        .read_columns::<u64>(&Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("puzzle-inputs").join(path))?
+        .remove(0);
+    crate::alloc_profile::parsed();
+
+    // TODO testing
+    // let input = vec![123u64];
+
+    // the last (up to) 4 deltas, packed into one key
+    let packer = Packer::new(-9..=9, 4);
+    let mut last_sequence = 0;
+    // index = sequence of last deltas
+    // value = sum over buyers of each one's max ones digit
+    let mut best: DenseMap<u64> = DenseMap::new(packer.key_count());
+    // for each sequence, the last buyer that saw it and the max ones digit they got
+    let mut seen_by = vec![(usize::MAX, 0u64); packer.key_count()];
+    for (buyer, number) in input.into_iter().enumerate() {
+        let mut current = number;
+        // println!("TODO start = {}", current);
+        // TODO testing
+        // for _ in 0..10 {
+        for _ in 0..2000 {
+            let next = multiply_step(current, 64);
+            let next = divide_step(next, 32);
+            let next = multiply_step(next, 2048);
+
+            let next_ones = next % 10;
+            let current_ones = current % 10;
+            let delta = (next_ones as i64) - (current_ones as i64);
+
+            current = next;
+
+            {
+                let total = best.entry_or_default(last_sequence);
+                let (seen_buyer, seen_ones) = &mut seen_by[last_sequence];
+                if *seen_buyer != buyer {
+                    *seen_buyer = buyer;
+                    *seen_ones = current_ones;
+                    *total += current_ones;
+                } else if current_ones > *seen_ones {
+                    *total += current_ones - *seen_ones;
+                    *seen_ones = current_ones;
+                }
+            }
+
+            last_sequence = packer.push(last_sequence, delta).ok_or("delta out of range")?;
+        }
+    }
+
+    Ok(*best.values().max().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::do_it;
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!(do_it("day22b-sample.txt").unwrap(), 23);
+    }
+
+    #[test]
+    pub fn test_real() {
+        // 2002, too high
+        assert_eq!(do_it("day22.txt").unwrap(), 0);
+    }
+}
@@ -1,5 +1,4 @@
 use std::{
-    collections::{HashMap, HashSet},
     env,
     fmt::Debug,
     fs::File,
@@ -9,8 +8,10 @@ use std::{
     str::Utf8Error,
 };
 
+use crate::fast_map::{FastHashMap, FastHashSet};
+
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -50,8 +51,7 @@ impl From<Utf8Error> for Error {
     }
 }
 
-#[allow(dead_code)]
-fn do_it(path: &str) -> Result<String> {
+pub(crate) fn do_it(path: &str) -> Result<String> {
     let file_contents = BufReader::new(File::open(
         Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("puzzle-inputs").join(path),
     )?)
@@ -72,7 +72,7 @@ fn do_it(path: &str) -> Result<String> {
         .collect::<Vec<_>>();
 
     // graph node name to graph node index
-    let mut name_to_index = HashMap::new();
+    let mut name_to_index = FastHashMap::default();
     // graph node index to graph node name
     let mut index_to_name = Vec::new();
     // graph node index to set of neighboring graph node indices
@@ -88,14 +88,14 @@ fn do_it(path: &str) -> Result<String> {
 
         let a_i = *name_to_index.entry(a).or_insert_with(|| {
             let result = next;
-            connections.push(HashSet::new());
+            connections.push(FastHashSet::default());
             index_to_name.push(a.to_string());
             next += 1;
             result
         });
         let b_i = *name_to_index.entry(b).or_insert_with(|| {
             let result = next;
-            connections.push(HashSet::new());
+            connections.push(FastHashSet::default());
             index_to_name.push(b.to_string());
             next += 1;
             result
@@ -105,11 +105,12 @@ fn do_it(path: &str) -> Result<String> {
         connections[b_i].insert(a_i);
     }
 
-    // turn sets into vectors, so we can sort them
-    let connections = connections
-        .into_iter()
+    // turn sets into vectors, so we can sort them, keeping the sets for membership checks
+    let neighbours = connections;
+    let connections = neighbours
+        .iter()
         .map(|c| {
-            let mut result = c.into_iter().collect::<Vec<_>>();
+            let mut result = c.iter().copied().collect::<Vec<_>>();
             result.sort();
             result
         })
@@ -117,10 +118,9 @@ fn do_it(path: &str) -> Result<String> {
 
     // iterate over all triplets
     // start with all indices
-    let mut triplets = HashSet::new();
-    for i1 in 0..connections.len() {
+    let mut triplets = FastHashSet::default();
+    for (i1, connections_i1) in connections.iter().enumerate() {
         // iterate over all pairs of neighbors of i1, such that they go in order i1 < i2 < i3
-        let connections_i1 = &connections[i1];
         for j2 in 0..connections_i1.len() {
             for j3 in (j2 + 1)..connections_i1.len() {
                 if j2 == j3 {
@@ -128,7 +128,7 @@ fn do_it(path: &str) -> Result<String> {
                 }
                 let i2 = connections_i1[j2];
                 let i3 = connections_i1[j3];
-                if connections[i2].contains(&i3) {
+                if neighbours[i2].contains(&i3) {
                     let mut sorted = [i1, i2, i3];
                     sorted.sort();
                     triplets.insert(sorted);
@@ -148,7 +148,7 @@ fn do_it(path: &str) -> Result<String> {
                 continue;
             }
             // if all current nodes have a connection to this node then it's part of the group
-            if group.iter().all(|g| connections[*g].contains(&i4)) {
+            if group.iter().all(|g| neighbours[*g].contains(&i4)) {
                 group.push(i4);
             }
         }
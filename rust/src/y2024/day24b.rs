@@ -14,7 +14,7 @@ use std::{
 use regex::Regex;
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -304,8 +304,7 @@ fn get_number_from_prefix(values: &HashMap<String, bool>, prefix: &str) -> u64 {
     result
 }
 
-#[allow(dead_code)]
-fn do_it<F>(path: &str, z_func: F) -> Result<String>
+pub(crate) fn do_it<F>(path: &str, z_func: F) -> Result<String>
 where
     F: Fn(u64, u64) -> u64,
 {
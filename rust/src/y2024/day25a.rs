@@ -11,7 +11,7 @@ use std::{
 
 
 #[derive(Debug, Clone)]
-struct Error(#[allow(dead_code)] String);
+pub(crate) struct Error(#[allow(dead_code)] String);
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -51,8 +51,7 @@ impl From<Utf8Error> for Error {
     }
 }
 
-#[allow(dead_code)]
-fn do_it<F>(path: &str, z_func: F) -> Result<u64>
+pub(crate) fn do_it<F>(path: &str, z_func: F) -> Result<u64>
 where
     F: Fn(u64, u64) -> u64,
 {
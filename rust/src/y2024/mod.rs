@@ -0,0 +1,53 @@
+pub(crate) mod day01;
+pub(crate) mod day01a;
+pub(crate) mod day01b;
+pub(crate) mod day02;
+pub(crate) mod day02a;
+pub(crate) mod day02b;
+pub(crate) mod day03;
+pub(crate) mod day03a;
+pub(crate) mod day03b;
+pub(crate) mod day04;
+pub(crate) mod day04a;
+pub(crate) mod day04b;
+pub(crate) mod day05;
+pub(crate) mod day05a;
+pub(crate) mod day05b;
+pub(crate) mod day06a;
+pub(crate) mod day06b;
+pub(crate) mod day07a;
+pub(crate) mod day07b;
+pub(crate) mod day08a;
+pub(crate) mod day08b;
+pub(crate) mod day09a;
+pub(crate) mod day09b;
+pub(crate) mod day10a;
+pub(crate) mod day10b;
+pub(crate) mod day11a;
+pub(crate) mod day11b;
+pub(crate) mod day12a;
+pub(crate) mod day12b;
+pub(crate) mod day13a;
+pub(crate) mod day13b;
+pub(crate) mod day14a;
+pub(crate) mod day14b;
+pub(crate) mod day15a;
+pub(crate) mod day15b;
+pub(crate) mod day16a;
+pub(crate) mod day16b;
+pub(crate) mod day17a;
+pub(crate) mod day17b;
+pub(crate) mod day18a;
+pub(crate) mod day18b;
+pub(crate) mod day19a;
+pub(crate) mod day19b;
+pub(crate) mod day20a;
+pub(crate) mod day20b;
+pub(crate) mod day21a;
+pub(crate) mod day22a;
+pub(crate) mod day22b;
+pub(crate) mod day23a;
+pub(crate) mod day23b;
+pub(crate) mod day24a;
+pub(crate) mod day24b;
+pub(crate) mod day25a;